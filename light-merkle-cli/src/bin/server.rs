@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use light_merkle_cli::{
+    leaves::{read_leaves, LeavesFormat},
+    options::TreeOptions,
+    server::{new_tree, Server},
+};
+
+#[derive(Parser)]
+#[clap(name = "light-merkle-server")]
+struct Options {
+    #[clap(flatten)]
+    tree: TreeOptions,
+    /// Port to listen on (on localhost).
+    #[clap(long, default_value_t = 8900)]
+    port: u16,
+    /// File with leaves to append on startup.
+    #[clap(long)]
+    leaves: Option<PathBuf>,
+    #[clap(value_enum, long, default_value_t = LeavesFormat::Hex)]
+    format: LeavesFormat,
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let opts = Options::parse();
+    opts.tree.check_height()?;
+
+    let mut server = Server::new(new_tree(&opts.tree));
+    if let Some(leaves) = opts.leaves {
+        server.append_leaves(&read_leaves(&leaves, opts.format)?)?;
+    }
+
+    server.serve(opts.port)
+}
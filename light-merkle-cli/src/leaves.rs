@@ -0,0 +1,58 @@
+use std::{fs, path::Path};
+
+use clap::ValueEnum;
+
+use light_merkle_tree::{hasher::Hasher, HASH_LEN};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum LeavesFormat {
+    /// One hex-encoded leaf per line, optionally prefixed with `0x`.
+    Hex,
+    /// Concatenated 32-byte leaves.
+    Binary,
+}
+
+/// Parses a hex-encoded 32-byte hash, optionally prefixed with `0x`.
+pub fn parse_hash(s: &str) -> Result<[u8; HASH_LEN], anyhow::Error> {
+    let s = s.trim();
+    let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+    <[u8; HASH_LEN]>::try_from(bytes)
+        .map_err(|bytes| anyhow::anyhow!("expected {HASH_LEN} bytes, got {}", bytes.len()))
+}
+
+/// Checks whether the given leaf can be hashed with `H`, e.g. whether it's
+/// a field element for Poseidon.
+pub fn check_leaf<H: Hasher>(leaf: &[u8; HASH_LEN]) -> Result<(), anyhow::Error> {
+    anyhow::ensure!(
+        H::is_valid_input(leaf),
+        "{} is not a valid input of the hash function",
+        hex::encode(leaf)
+    );
+    Ok(())
+}
+
+/// Reads leaves from the given file.
+pub fn read_leaves(
+    path: &Path,
+    format: LeavesFormat,
+) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+    match format {
+        LeavesFormat::Hex => fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(parse_hash)
+            .collect(),
+        LeavesFormat::Binary => {
+            let bytes = fs::read(path)?;
+            anyhow::ensure!(
+                bytes.len() % HASH_LEN == 0,
+                "size of {:?} is not a multiple of {HASH_LEN}",
+                path
+            );
+            Ok(bytes
+                .chunks_exact(HASH_LEN)
+                .map(|chunk| chunk.try_into().unwrap())
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod leaves;
+pub mod options;
+pub mod proof;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod tree;
@@ -0,0 +1,241 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use serde::Serialize;
+
+use light_merkle_cli::{
+    leaves::{check_leaf, parse_hash, read_leaves, LeavesFormat},
+    options::{EmptyLeaf, Hash, TreeOptions},
+    proof::Proof,
+    tree::FullTree,
+};
+use light_merkle_tree::{
+    config::{
+        Blake3MerkleTreeConfig, Blake3ZeroMerkleTreeConfig, KeccakMerkleTreeConfig,
+        KeccakZeroMerkleTreeConfig, MerkleTreeConfig, PoseidonMerkleTreeConfig,
+        PoseidonZeroMerkleTreeConfig, Sha256MerkleTreeConfig, Sha256ZeroMerkleTreeConfig,
+    },
+    hasher::{Blake3, Hasher, Keccak, Poseidon, Sha256},
+    mixed_root::compute_root,
+    MerkleTree, HASH_LEN,
+};
+
+#[derive(Debug, Parser)]
+struct LeavesOptions {
+    /// File with leaves.
+    leaves: PathBuf,
+    #[clap(value_enum, long, default_value_t = LeavesFormat::Hex)]
+    format: LeavesFormat,
+}
+
+#[derive(Parser)]
+#[clap(name = "light-merkle")]
+struct Options {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Print the root and the `filled_subtrees` frontier of a tree with the
+    /// given leaves.
+    Root {
+        #[clap(flatten)]
+        tree: TreeOptions,
+        #[clap(flatten)]
+        leaves: LeavesOptions,
+    },
+    /// Print the proof of the leaf under the given index.
+    Prove {
+        #[clap(flatten)]
+        tree: TreeOptions,
+        #[clap(flatten)]
+        leaves: LeavesOptions,
+        #[clap(long)]
+        index: usize,
+    },
+    /// Verify a proof printed by the `prove` subcommand.
+    Verify {
+        #[clap(flatten)]
+        tree: TreeOptions,
+        /// File with the proof.
+        proof: PathBuf,
+    },
+    /// Print the root and the `filled_subtrees` frontier after each insert.
+    Replay {
+        #[clap(flatten)]
+        tree: TreeOptions,
+        #[clap(flatten)]
+        leaves: LeavesOptions,
+    },
+}
+
+impl Command {
+    fn tree_options(&self) -> &TreeOptions {
+        match self {
+            Command::Root { tree, .. }
+            | Command::Prove { tree, .. }
+            | Command::Verify { tree, .. }
+            | Command::Replay { tree, .. } => tree,
+        }
+    }
+}
+
+/// State of a `MerkleTree` after an insert.
+#[derive(Serialize)]
+struct TreeState {
+    next_index: u64,
+    root: String,
+    filled_subtrees: Vec<String>,
+}
+
+impl TreeState {
+    fn new<H, C>(merkle_tree: &MerkleTree<H, C>) -> Self
+    where
+        H: Hasher,
+        C: MerkleTreeConfig,
+    {
+        TreeState {
+            next_index: merkle_tree.next_index,
+            root: hex::encode(merkle_tree.last_root()),
+            filled_subtrees: merkle_tree.filled_subtrees[..merkle_tree.height as usize]
+                .iter()
+                .map(hex::encode)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Verification {
+    valid: bool,
+    root: String,
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), anyhow::Error> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Inserts leaves into a `MerkleTree` in pairs, calling `f` after each
+/// insert. If the number of leaves is odd, the last one is paired with the
+/// empty leaf.
+fn insert_leaves<H, C>(
+    height: usize,
+    leaves: &[[u8; HASH_LEN]],
+    mut f: impl FnMut(&MerkleTree<H, C>),
+) -> Result<MerkleTree<H, C>, anyhow::Error>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    anyhow::ensure!(
+        leaves.len() <= 1 << height,
+        "{} leaves don't fit in a Merkle tree of height {}",
+        leaves.len(),
+        height
+    );
+
+    for leaf in leaves {
+        check_leaf::<H>(leaf)?;
+    }
+
+    let mut merkle_tree = MerkleTree::<H, C>::new(height);
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair.get(1).copied().unwrap_or(C::EMPTY_LEAF));
+        f(&merkle_tree);
+    }
+
+    Ok(merkle_tree)
+}
+
+fn run<H, C>(command: Command) -> Result<(), anyhow::Error>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    command.tree_options().check_height()?;
+    let height = command.tree_options().height;
+
+    match command {
+        Command::Root { leaves, .. } => {
+            let leaves = read_leaves(&leaves.leaves, leaves.format)?;
+            let merkle_tree = insert_leaves::<H, C>(height, &leaves, |_| {})?;
+            print_json(&TreeState::new(&merkle_tree))?;
+        }
+        Command::Prove { leaves, index, .. } => {
+            let leaves = read_leaves(&leaves.leaves, leaves.format)?;
+            let tree = FullTree::<H, C>::from_leaves(height, &leaves)?;
+            let proof = tree.proof(index)?;
+            print_json(&Proof::new(
+                tree.leaves()[index],
+                index,
+                tree.root(),
+                &proof,
+            ))?;
+        }
+        Command::Verify { proof, .. } => {
+            let proof: Proof = serde_json::from_str(&fs::read_to_string(proof)?)?;
+            anyhow::ensure!(
+                proof.proof.len() == height,
+                "proof has {} nodes, expected {}",
+                proof.proof.len(),
+                height
+            );
+            anyhow::ensure!(
+                proof.index < 1 << height,
+                "leaf {} doesn't fit in a Merkle tree of height {}",
+                proof.index,
+                height
+            );
+
+            let siblings = proof
+                .proof
+                .iter()
+                .map(|sibling| parse_hash(sibling))
+                .collect::<Result<Vec<_>, _>>()?;
+            let leaf = parse_hash(&proof.leaf)?;
+            for node in siblings.iter().chain([&leaf]) {
+                check_leaf::<H>(node)?;
+            }
+            let root = compute_root::<H, C>(leaf, proof.index as u64, &siblings);
+            let valid = root == parse_hash(&proof.root)?;
+
+            print_json(&Verification {
+                valid,
+                root: hex::encode(root),
+            })?;
+            anyhow::ensure!(valid, "invalid proof");
+        }
+        Command::Replay { leaves, .. } => {
+            let leaves = read_leaves(&leaves.leaves, leaves.format)?;
+            let mut states = vec![];
+            insert_leaves::<H, C>(height, &leaves, |merkle_tree| {
+                states.push(TreeState::new(merkle_tree))
+            })?;
+            print_json(&states)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let opts = Options::parse();
+    let tree = opts.command.tree_options();
+
+    match (tree.hash, tree.empty_leaf) {
+        (Hash::Sha256, EmptyLeaf::Default) => run::<Sha256, Sha256MerkleTreeConfig>(opts.command),
+        (Hash::Sha256, EmptyLeaf::Zero) => run::<Sha256, Sha256ZeroMerkleTreeConfig>(opts.command),
+        (Hash::Keccak, EmptyLeaf::Default) => run::<Keccak, KeccakMerkleTreeConfig>(opts.command),
+        (Hash::Keccak, EmptyLeaf::Zero) => run::<Keccak, KeccakZeroMerkleTreeConfig>(opts.command),
+        (Hash::Blake3, EmptyLeaf::Default) => run::<Blake3, Blake3MerkleTreeConfig>(opts.command),
+        (Hash::Blake3, EmptyLeaf::Zero) => run::<Blake3, Blake3ZeroMerkleTreeConfig>(opts.command),
+        (Hash::Poseidon, EmptyLeaf::Default) => {
+            run::<Poseidon, PoseidonMerkleTreeConfig>(opts.command)
+        }
+        (Hash::Poseidon, EmptyLeaf::Zero) => {
+            run::<Poseidon, PoseidonZeroMerkleTreeConfig>(opts.command)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use clap::{Parser, ValueEnum};
+
+use light_merkle_tree::MAX_HEIGHT;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Hash {
+    Sha256,
+    Keccak,
+    Blake3,
+    Poseidon,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum EmptyLeaf {
+    /// Empty leaf derived from the `[1; 32] || [1; 32]` preimage.
+    Default,
+    /// All-zero empty leaf.
+    Zero,
+}
+
+#[derive(Debug, Parser)]
+pub struct TreeOptions {
+    #[clap(value_enum, long, default_value_t = Hash::Sha256)]
+    pub hash: Hash,
+    #[clap(value_enum, long, default_value_t = EmptyLeaf::Default)]
+    pub empty_leaf: EmptyLeaf,
+    #[clap(long)]
+    pub height: usize,
+}
+
+impl TreeOptions {
+    pub fn check_height(&self) -> Result<(), anyhow::Error> {
+        anyhow::ensure!(
+            (1..=MAX_HEIGHT).contains(&self.height),
+            "height has to be between 1 and {MAX_HEIGHT}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use light_merkle_tree::HASH_LEN;
+
+/// Proof of inclusion of a leaf, with hex-encoded hashes.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Proof {
+    pub leaf: String,
+    pub index: usize,
+    pub root: String,
+    /// Siblings of the leaf, starting from the bottom.
+    pub proof: Vec<String>,
+}
+
+impl Proof {
+    pub fn new(
+        leaf: [u8; HASH_LEN],
+        index: usize,
+        root: [u8; HASH_LEN],
+        proof: &[[u8; HASH_LEN]],
+    ) -> Self {
+        Proof {
+            leaf: hex::encode(leaf),
+            index,
+            root: hex::encode(root),
+            proof: proof.iter().map(hex::encode).collect(),
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use serde_json::{json, Value};
+
+use light_merkle_tree::{
+    config::{
+        Blake3MerkleTreeConfig, Blake3ZeroMerkleTreeConfig, KeccakMerkleTreeConfig,
+        KeccakZeroMerkleTreeConfig, MerkleTreeConfig, PoseidonMerkleTreeConfig,
+        PoseidonZeroMerkleTreeConfig, Sha256MerkleTreeConfig, Sha256ZeroMerkleTreeConfig,
+    },
+    hasher::{Blake3, Hasher, Keccak, Poseidon, Sha256},
+    HASH_LEN,
+};
+
+use crate::{
+    leaves::{check_leaf, parse_hash},
+    options::{EmptyLeaf, Hash, TreeOptions},
+    proof::Proof,
+    tree::FullTree,
+};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_ERROR: i64 = -32000;
+
+/// Object-safe interface of [`FullTree`](crate::tree::FullTree), which lets
+/// the server pick the hasher and config at runtime.
+pub trait ProofTree {
+    fn check_leaf(&self, leaf: &[u8; HASH_LEN]) -> Result<(), anyhow::Error>;
+    fn append(&mut self, leaf: [u8; HASH_LEN]) -> Result<(), anyhow::Error>;
+    fn root(&self) -> [u8; HASH_LEN];
+    fn proof(&self, index: usize) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error>;
+    fn leaves(&self) -> &[[u8; HASH_LEN]];
+    fn capacity(&self) -> usize;
+}
+
+impl<H, C> ProofTree for FullTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn check_leaf(&self, leaf: &[u8; HASH_LEN]) -> Result<(), anyhow::Error> {
+        check_leaf::<H>(leaf)
+    }
+
+    fn append(&mut self, leaf: [u8; HASH_LEN]) -> Result<(), anyhow::Error> {
+        FullTree::append(self, leaf)
+    }
+
+    fn root(&self) -> [u8; HASH_LEN] {
+        FullTree::root(self)
+    }
+
+    fn proof(&self, index: usize) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+        FullTree::proof(self, index)
+    }
+
+    fn leaves(&self) -> &[[u8; HASH_LEN]] {
+        FullTree::leaves(self)
+    }
+
+    fn capacity(&self) -> usize {
+        FullTree::capacity(self)
+    }
+}
+
+/// Creates an empty tree with the hasher and config given in options.
+pub fn new_tree(opts: &TreeOptions) -> Box<dyn ProofTree> {
+    let height = opts.height;
+    match (opts.hash, opts.empty_leaf) {
+        (Hash::Sha256, EmptyLeaf::Default) => {
+            Box::new(FullTree::<Sha256, Sha256MerkleTreeConfig>::new(height))
+        }
+        (Hash::Sha256, EmptyLeaf::Zero) => {
+            Box::new(FullTree::<Sha256, Sha256ZeroMerkleTreeConfig>::new(height))
+        }
+        (Hash::Keccak, EmptyLeaf::Default) => {
+            Box::new(FullTree::<Keccak, KeccakMerkleTreeConfig>::new(height))
+        }
+        (Hash::Keccak, EmptyLeaf::Zero) => {
+            Box::new(FullTree::<Keccak, KeccakZeroMerkleTreeConfig>::new(height))
+        }
+        (Hash::Blake3, EmptyLeaf::Default) => {
+            Box::new(FullTree::<Blake3, Blake3MerkleTreeConfig>::new(height))
+        }
+        (Hash::Blake3, EmptyLeaf::Zero) => {
+            Box::new(FullTree::<Blake3, Blake3ZeroMerkleTreeConfig>::new(height))
+        }
+        (Hash::Poseidon, EmptyLeaf::Default) => {
+            Box::new(FullTree::<Poseidon, PoseidonMerkleTreeConfig>::new(height))
+        }
+        (Hash::Poseidon, EmptyLeaf::Zero) => Box::new(FullTree::<
+            Poseidon,
+            PoseidonZeroMerkleTreeConfig,
+        >::new(height)),
+    }
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        RpcError {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// JSON-RPC server answering proof requests for a tree kept in memory.
+///
+/// Supported methods:
+///
+/// * `getRoot` - returns the current root.
+/// * `getProof(index)` - returns the proof of the leaf under `index`.
+/// * `getLeafIndex(leaf)` - returns the index of the first occurrence of
+///   `leaf`, or `null`.
+/// * `appendLeaves(leaves)` - appends leaves and returns the new root.
+pub struct Server {
+    tree: Box<dyn ProofTree>,
+    leaf_indices: HashMap<[u8; HASH_LEN], usize>,
+}
+
+impl Server {
+    pub fn new(tree: Box<dyn ProofTree>) -> Self {
+        let leaf_indices = tree
+            .leaves()
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, leaf)| (*leaf, index))
+            .collect();
+        Server { tree, leaf_indices }
+    }
+
+    /// Appends leaves to the tree. Either all leaves are appended or none.
+    pub fn append_leaves(&mut self, leaves: &[[u8; HASH_LEN]]) -> Result<(), anyhow::Error> {
+        anyhow::ensure!(
+            self.tree.leaves().len() + leaves.len() <= self.tree.capacity(),
+            "{} leaves don't fit in the Merkle tree",
+            leaves.len()
+        );
+        // Check all leaves before appending any of them.
+        for leaf in leaves {
+            self.tree.check_leaf(leaf)?;
+        }
+
+        for leaf in leaves {
+            let index = self.tree.leaves().len();
+            self.tree.append(*leaf)?;
+            self.leaf_indices.entry(*leaf).or_insert(index);
+        }
+
+        Ok(())
+    }
+
+    fn param(params: &Value, i: usize) -> Result<&Value, RpcError> {
+        params
+            .get(i)
+            .ok_or_else(|| RpcError::new(INVALID_PARAMS, format!("missing parameter {i}")))
+    }
+
+    fn hash_param(param: &Value) -> Result<[u8; HASH_LEN], RpcError> {
+        param
+            .as_str()
+            .ok_or_else(|| RpcError::new(INVALID_PARAMS, "expected a hex string"))
+            .and_then(|s| parse_hash(s).map_err(|e| RpcError::new(INVALID_PARAMS, e)))
+    }
+
+    fn call(&mut self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        match method {
+            "getRoot" => Ok(json!(hex::encode(self.tree.root()))),
+            "getProof" => {
+                let index = Self::param(params, 0)?
+                    .as_u64()
+                    .ok_or_else(|| RpcError::new(INVALID_PARAMS, "expected an index"))?
+                    as usize;
+                let proof = self
+                    .tree
+                    .proof(index)
+                    .map_err(|e| RpcError::new(SERVER_ERROR, e))?;
+                let proof = Proof::new(self.tree.leaves()[index], index, self.tree.root(), &proof);
+                Ok(serde_json::to_value(proof).unwrap())
+            }
+            "getLeafIndex" => {
+                let leaf = Self::hash_param(Self::param(params, 0)?)?;
+                Ok(json!(self.leaf_indices.get(&leaf)))
+            }
+            "appendLeaves" => {
+                let leaves = Self::param(params, 0)?
+                    .as_array()
+                    .ok_or_else(|| RpcError::new(INVALID_PARAMS, "expected an array of leaves"))?
+                    .iter()
+                    .map(Self::hash_param)
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.append_leaves(&leaves)
+                    .map_err(|e| RpcError::new(SERVER_ERROR, e))?;
+                Ok(json!(hex::encode(self.tree.root())))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("method {method} not found"),
+            )),
+        }
+    }
+
+    /// Handles a JSON-RPC request and returns the response.
+    pub fn handle(&mut self, request: &str) -> Value {
+        let request: Value = match serde_json::from_str(request) {
+            Ok(request) => request,
+            Err(e) => return error_response(Value::Null, RpcError::new(PARSE_ERROR, e)),
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+
+        let method = match request.get("method").and_then(Value::as_str) {
+            Some(method) => method,
+            None => return error_response(id, RpcError::new(INVALID_REQUEST, "missing method")),
+        };
+        let params = request.get("params").cloned().unwrap_or(json!([]));
+
+        match self.call(method, &params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => error_response(id, e),
+        }
+    }
+
+    /// Serves JSON-RPC requests over HTTP on localhost. Prints the address
+    /// the server listens on, which is useful when `port` is 0.
+    pub fn serve(mut self, port: u16) -> Result<(), anyhow::Error> {
+        let server = tiny_http::Server::http(SocketAddr::from(([127, 0, 0, 1], port)))
+            .map_err(|e| anyhow::anyhow!(e))?;
+        println!("Listening on http://{}", server.server_addr());
+
+        let content_type =
+            tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+
+        for mut request in server.incoming_requests() {
+            let mut body = String::new();
+            let response = match request.as_reader().read_to_string(&mut body) {
+                Ok(_) => self.handle(&body),
+                Err(e) => error_response(Value::Null, RpcError::new(PARSE_ERROR, e)),
+            };
+
+            let response = tiny_http::Response::from_string(response.to_string())
+                .with_header(content_type.clone());
+            // A client which went away shouldn't stop serving others.
+            if let Err(e) = request.respond(response) {
+                eprintln!("Failed to respond: {e}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
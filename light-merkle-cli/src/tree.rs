@@ -0,0 +1,112 @@
+use std::marker::PhantomData;
+
+use light_merkle_tree::{config::MerkleTreeConfig, hasher::Hasher, HASH_LEN};
+
+use crate::leaves::check_leaf;
+
+/// Merkle tree keeping all its nodes in memory, which makes it possible to
+/// produce proofs. Nodes of empty subtrees are not stored, `C::ZERO_BYTES`
+/// are used instead.
+pub struct FullTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    height: usize,
+    /// Nodes on each level, starting with leaves.
+    levels: Vec<Vec<[u8; HASH_LEN]>>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> FullTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub fn new(height: usize) -> Self {
+        Self {
+            height,
+            levels: vec![Vec::new(); height + 1],
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    pub fn from_leaves(height: usize, leaves: &[[u8; HASH_LEN]]) -> Result<Self, anyhow::Error> {
+        let mut tree = Self::new(height);
+        for leaf in leaves {
+            tree.append(*leaf)?;
+        }
+        Ok(tree)
+    }
+
+    /// Maximum number of leaves.
+    pub fn capacity(&self) -> usize {
+        1 << self.height
+    }
+
+    pub fn leaves(&self) -> &[[u8; HASH_LEN]] {
+        &self.levels[0]
+    }
+
+    /// Appends a leaf and updates the path from it to the root.
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) -> Result<(), anyhow::Error> {
+        anyhow::ensure!(
+            self.levels[0].len() < self.capacity(),
+            "Merkle tree of height {} is full",
+            self.height
+        );
+        check_leaf::<H>(&leaf)?;
+
+        let mut index = self.levels[0].len();
+        self.levels[0].push(leaf);
+
+        for level in 0..self.height {
+            let parent = index / 2;
+            let left = self.node(level, parent * 2);
+            let right = self.node(level, parent * 2 + 1);
+            let hash = C::hash_pair::<H>(&left, &right);
+
+            if parent < self.levels[level + 1].len() {
+                self.levels[level + 1][parent] = hash;
+            } else {
+                self.levels[level + 1].push(hash);
+            }
+            index = parent;
+        }
+
+        Ok(())
+    }
+
+    fn node(&self, level: usize, index: usize) -> [u8; HASH_LEN] {
+        self.levels[level]
+            .get(index)
+            .copied()
+            .unwrap_or(C::ZERO_BYTES[level])
+    }
+
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        if self.levels[0].is_empty() {
+            return C::ZERO_BYTES[C::empty_root_level(self.height)];
+        }
+
+        self.node(self.height, 0)
+    }
+
+    /// Returns siblings of the leaf under the given index, starting from the
+    /// bottom.
+    pub fn proof(&self, index: usize) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+        anyhow::ensure!(
+            index < self.levels[0].len(),
+            "leaf {} doesn't exist, the tree has {} leaves",
+            index,
+            self.levels[0].len()
+        );
+
+        Ok((0..self.height)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect())
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+use light_merkle_cli::tree::FullTree;
+use light_merkle_tree::{
+    config::{Sha256MerkleTreeConfig, Sha256ZeroMerkleTreeConfig},
+    hasher::Sha256,
+    MerkleTree,
+};
+use serde_json::Value;
+
+fn light_merkle(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_light-merkle"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn write_leaves(name: &str, leaves: &[[u8; 32]]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("light-merkle-{}-{}", std::process::id(), name));
+    let contents: Vec<String> = leaves.iter().map(hex::encode).collect();
+    fs::write(&path, contents.join("\n")).unwrap();
+    path
+}
+
+fn leaves() -> Vec<[u8; 32]> {
+    (0..6u8).map(|i| [i + 1; 32]).collect()
+}
+
+#[test]
+fn test_root_and_replay() {
+    let path = write_leaves("root", &leaves());
+
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(4);
+    let mut roots = vec![];
+    for pair in leaves().chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        roots.push(hex::encode(merkle_tree.last_root()));
+    }
+
+    let output = light_merkle(&["root", "--height", "4", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    let root: Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(root["root"], roots[2]);
+    assert_eq!(root["next_index"], 6);
+    assert_eq!(root["filled_subtrees"].as_array().unwrap().len(), 4);
+
+    let output = light_merkle(&["replay", "--height", "4", path.to_str().unwrap()]);
+    assert!(output.status.success());
+    let states: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let replayed_roots: Vec<&str> = states
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|state| state["root"].as_str().unwrap())
+        .collect();
+    assert_eq!(replayed_roots, roots);
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_prove_and_verify() {
+    let path = write_leaves("prove", &leaves());
+
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(4);
+    for pair in leaves().chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+    }
+
+    for index in 0..6 {
+        let output = light_merkle(&[
+            "prove",
+            "--height",
+            "4",
+            "--index",
+            &index.to_string(),
+            path.to_str().unwrap(),
+        ]);
+        assert!(output.status.success());
+        let proof: Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(proof["root"], hex::encode(merkle_tree.last_root()));
+        assert_eq!(proof["leaf"], hex::encode(leaves()[index]));
+
+        let proof_path = path.with_extension("proof");
+        fs::write(&proof_path, &output.stdout).unwrap();
+        let output = light_merkle(&["verify", "--height", "4", proof_path.to_str().unwrap()]);
+        assert!(output.status.success());
+
+        // Index which selects the same path, but is out of range.
+        let mut proof = proof;
+        proof["index"] = Value::from(index + 16);
+        fs::write(&proof_path, proof.to_string()).unwrap();
+        let output = light_merkle(&["verify", "--height", "4", proof_path.to_str().unwrap()]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("doesn't fit"));
+
+        // Tamper with the leaf.
+        proof["index"] = Value::from(index);
+        proof["leaf"] = Value::String(hex::encode([42u8; 32]));
+        fs::write(&proof_path, proof.to_string()).unwrap();
+        let output = light_merkle(&["verify", "--height", "4", proof_path.to_str().unwrap()]);
+        assert!(!output.status.success());
+
+        fs::remove_file(proof_path).unwrap();
+    }
+
+    // Index out of range.
+    let output = light_merkle(&[
+        "prove",
+        "--height",
+        "4",
+        "--index",
+        "6",
+        path.to_str().unwrap(),
+    ]);
+    assert!(!output.status.success());
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_poseidon_invalid_leaf() {
+    // Leaves have to be smaller than the BN254 modulus.
+    let path = write_leaves("poseidon", &[[1; 32], [0xff; 32]]);
+
+    for command in ["root", "prove", "replay"] {
+        let mut args = vec![command, "--hash", "poseidon", "--height", "4"];
+        if command == "prove" {
+            args.extend(["--index", "0"]);
+        }
+        args.push(path.to_str().unwrap());
+        let output = light_merkle(&args);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains("not a valid input"));
+    }
+
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_empty_root() {
+    // Roots of empty trees match the `MerkleTree`.
+    for height in 1..=4 {
+        assert_eq!(
+            FullTree::<Sha256, Sha256MerkleTreeConfig>::new(height).root(),
+            MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(height).last_root()
+        );
+        assert_eq!(
+            FullTree::<Sha256, Sha256ZeroMerkleTreeConfig>::new(height).root(),
+            MerkleTree::<Sha256, Sha256ZeroMerkleTreeConfig>::new(height).last_root()
+        );
+    }
+}
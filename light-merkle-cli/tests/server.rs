@@ -0,0 +1,146 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+};
+
+use light_merkle_tree::{config::Sha256MerkleTreeConfig, hasher::Sha256, MerkleTree};
+use serde_json::{json, Value};
+
+/// Server process, killed on drop.
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn spawn(args: &[&str]) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_light-merkle-server"))
+            .args(["--port", "0"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.as_mut().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("Listening on http://")
+            .unwrap()
+            .to_string();
+
+        Server { child, addr }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Value {
+        let body =
+            json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string();
+
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "POST / HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.addr,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        serde_json::from_str(body).unwrap()
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_server() {
+    let leaves: Vec<[u8; 32]> = (0..4u8).map(|i| [i + 1; 32]).collect();
+    let path = std::env::temp_dir().join(format!("light-merkle-server-{}", std::process::id()));
+    let contents: Vec<String> = leaves.iter().map(hex::encode).collect();
+    fs::write(&path, contents.join("\n")).unwrap();
+
+    let server = Server::spawn(&["--height", "3", "--leaves", path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(3);
+    merkle_tree.insert(leaves[0], leaves[1]);
+    merkle_tree.insert(leaves[2], leaves[3]);
+
+    let response = server.call("getRoot", json!([]));
+    assert_eq!(response["result"], hex::encode(merkle_tree.last_root()));
+
+    let response = server.call("getLeafIndex", json!([hex::encode(leaves[2])]));
+    assert_eq!(response["result"], 2);
+    let response = server.call("getLeafIndex", json!([hex::encode([42u8; 32])]));
+    assert_eq!(response["result"], Value::Null);
+
+    // Append leaves and check the root and proofs against `MerkleTree`.
+    let response = server.call(
+        "appendLeaves",
+        json!([[hex::encode([5u8; 32]), hex::encode([6u8; 32])]]),
+    );
+    merkle_tree.insert([5u8; 32], [6u8; 32]);
+    assert_eq!(response["result"], hex::encode(merkle_tree.last_root()));
+
+    let response = server.call("getProof", json!([4]));
+    let proof = &response["result"];
+    assert_eq!(proof["leaf"], hex::encode([5u8; 32]));
+    assert_eq!(proof["root"], hex::encode(merkle_tree.last_root()));
+
+    let siblings: Vec<[u8; 32]> = proof["proof"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|sibling| {
+            hex::decode(sibling.as_str().unwrap())
+                .unwrap()
+                .try_into()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(siblings.len(), 3);
+    let h1 = merkle_tree.hash([5u8; 32], siblings[0]);
+    let h2 = merkle_tree.hash(h1, siblings[1]);
+    let root = merkle_tree.hash(siblings[2], h2);
+    assert_eq!(root, merkle_tree.last_root());
+
+    // Errors.
+    let response = server.call("getProof", json!([6]));
+    assert_eq!(response["error"]["code"], -32000);
+    let response = server.call("getLeaf", json!([0]));
+    assert_eq!(response["error"]["code"], -32601);
+    let response = server.call("appendLeaves", json!([vec![hex::encode([7u8; 32]); 3]]));
+    assert_eq!(response["error"]["code"], -32000);
+}
+
+#[test]
+fn test_server_append_invalid_leaf() {
+    let server = Server::spawn(&["--height", "3", "--hash", "poseidon"]);
+    let root = server.call("getRoot", json!([]))["result"].clone();
+
+    // The last leaf is not a BN254 field element, so none of the leaves is
+    // appended.
+    let response = server.call(
+        "appendLeaves",
+        json!([[hex::encode([1u8; 32]), hex::encode([0xffu8; 32])]]),
+    );
+    assert_eq!(response["error"]["code"], -32000);
+    assert_eq!(server.call("getRoot", json!([]))["result"], root);
+    let response = server.call("getLeafIndex", json!([hex::encode([1u8; 32])]));
+    assert_eq!(response["result"], Value::Null);
+
+    let response = server.call("appendLeaves", json!([[hex::encode([1u8; 32])]]));
+    assert_ne!(response["result"], root);
+}
@@ -0,0 +1,5 @@
+pub mod mmap;
+pub mod sqlite;
+
+pub use mmap::MmapStore;
+pub use sqlite::SqliteStore;
@@ -0,0 +1,267 @@
+use std::{
+    fs::{File, OpenOptions},
+    marker::PhantomData,
+    path::Path,
+};
+
+use memmap2::MmapMut;
+
+use light_merkle_tree::{
+    config::MerkleTreeConfig, constants::extended_zero_bytes, hasher::Hasher, HASH_LEN,
+};
+
+const MAGIC: &[u8; 8] = b"LMTNODES";
+
+/// Size of the header, which takes the whole first page of the file.
+pub const HEADER_SIZE: u64 = 4096;
+
+/// Maximum height of a tree in [`MmapStore`](MmapStore). The file of a tree
+/// with this height takes about 8 TiB, the next height would exceed the
+/// 16 TiB file size limit of ext4.
+pub const MAX_MMAP_HEIGHT: usize = 37;
+
+/// Returns the offset of the node under the given level and index in the
+/// file of a [`MmapStore`](MmapStore) with the given height.
+///
+/// Nodes are stored level by level, starting with leaves, so each level of
+/// a tree of height `h` occupies `2^(h - level) * HASH_LEN` bytes after the
+/// header.
+pub fn node_offset(height: usize, level: usize, index: u64) -> u64 {
+    // Levels below take `2^h + 2^(h-1) + ... + 2^(h-level+1)` nodes.
+    let nodes_below = (1u64 << (height + 1)) - (1u64 << (height + 1 - level));
+    HEADER_SIZE + (nodes_below + index) * HASH_LEN as u64
+}
+
+/// File-backed, memory-mapped node store of a full replica of
+/// [`MerkleTree`](light_merkle_tree::MerkleTree), meant for trees too big
+/// to keep in memory.
+///
+/// The file is sparse, nodes of empty subtrees are never written. Appends
+/// are crash-safe: new nodes are flushed before the number of leaves in the
+/// header, so after a crash the store reopens in the state of the last
+/// completed append.
+pub struct MmapStore<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    _file: File,
+    mmap: MmapMut,
+    height: usize,
+    next_index: u64,
+    /// `C::ZERO_BYTES` extended to the height of the tree.
+    zero_bytes: Vec<[u8; HASH_LEN]>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> MmapStore<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Opens the store in the given file, creating it if it doesn't exist.
+    /// Nodes on the path of the last leaf are recomputed, to discard partial
+    /// writes of an interrupted append.
+    pub fn open<P: AsRef<Path>>(path: P, height: usize) -> Result<Self, anyhow::Error> {
+        anyhow::ensure!(
+            (1..=MAX_MMAP_HEIGHT).contains(&height),
+            "height has to be between 1 and {MAX_MMAP_HEIGHT}"
+        );
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let len = node_offset(height, height, 1);
+        let new = file.metadata()?.len() == 0;
+        if new {
+            file.set_len(len)?;
+        } else {
+            anyhow::ensure!(
+                file.metadata()?.len() == len,
+                "file size doesn't match the Merkle tree of height {}",
+                height
+            );
+        }
+
+        // SAFETY: The file is not supposed to be modified by other processes
+        // while it's open.
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+
+        if new {
+            mmap[..8].copy_from_slice(MAGIC);
+            mmap[8..16].copy_from_slice(&(height as u64).to_le_bytes());
+            mmap[16..24].copy_from_slice(&0u64.to_le_bytes());
+            mmap.flush_range(0, HEADER_SIZE as usize)?;
+        } else {
+            anyhow::ensure!(&mmap[..8] == MAGIC, "not a Merkle tree node store");
+            let stored_height = u64::from_le_bytes(mmap[8..16].try_into().unwrap());
+            anyhow::ensure!(
+                stored_height == height as u64,
+                "store contains a Merkle tree of height {}, expected {}",
+                stored_height,
+                height
+            );
+        }
+        let next_index = u64::from_le_bytes(mmap[16..24].try_into().unwrap());
+        anyhow::ensure!(
+            next_index <= 1 << height,
+            "store contains {} leaves, more than a Merkle tree of height {} can hold",
+            next_index,
+            height
+        );
+
+        let zero_bytes = extended_zero_bytes::<H, C>(height);
+
+        let mut store = MmapStore {
+            _file: file,
+            mmap,
+            height,
+            next_index,
+            zero_bytes,
+            hasher: PhantomData,
+            config: PhantomData,
+        };
+        if next_index > 0 {
+            store.update_nodes(next_index - 1, next_index)?;
+        }
+
+        Ok(store)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of leaves in the tree.
+    pub fn next_index(&self) -> u64 {
+        self.next_index
+    }
+
+    fn node_at(&self, level: usize, index: u64, next_index: u64) -> [u8; HASH_LEN] {
+        // Number of nodes with at least one leaf below.
+        let filled = (next_index + (1 << level) - 1) >> level;
+        if index >= filled {
+            return self.zero_bytes[level];
+        }
+
+        let offset = node_offset(self.height, level, index) as usize;
+        self.mmap[offset..offset + HASH_LEN].try_into().unwrap()
+    }
+
+    /// Returns the node under the given level and index. Level 0 contains
+    /// leaves. Nodes of empty subtrees are taken from the zero bytes.
+    pub fn node(&self, level: usize, index: u64) -> [u8; HASH_LEN] {
+        self.node_at(level, index, self.next_index)
+    }
+
+    pub fn leaf(&self, index: u64) -> Option<[u8; HASH_LEN]> {
+        (index < self.next_index).then(|| self.node(0, index))
+    }
+
+    /// Returns the current root, the same as
+    /// [`MerkleTree::last_root`](light_merkle_tree::MerkleTree::last_root),
+    /// with the number of leaves mixed in for
+    /// [`MIX_IN_LENGTH`](MerkleTreeConfig::MIX_IN_LENGTH) configs.
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        if self.next_index == 0 {
+            return C::mix_root::<H>(&self.zero_bytes[C::empty_root_level(self.height)], 0);
+        }
+
+        C::mix_root::<H>(&self.node(self.height, 0), self.next_index)
+    }
+
+    /// Recomputes and flushes nodes above the leaves in `start..next_index`.
+    fn update_nodes(&mut self, start: u64, next_index: u64) -> Result<(), anyhow::Error> {
+        for level in 1..=self.height {
+            let first = start >> level;
+            let last = (next_index - 1) >> level;
+            for index in first..=last {
+                let left = self.node_at(level - 1, index * 2, next_index);
+                let right = self.node_at(level - 1, index * 2 + 1, next_index);
+                let offset = node_offset(self.height, level, index) as usize;
+                self.mmap[offset..offset + HASH_LEN]
+                    .copy_from_slice(&C::hash_pair::<H>(&left, &right));
+            }
+
+            let offset = node_offset(self.height, level, first) as usize;
+            self.mmap
+                .flush_range(offset, (last - first + 1) as usize * HASH_LEN)?;
+        }
+
+        Ok(())
+    }
+
+    /// Appends leaves. The append is committed only after all new nodes are
+    /// flushed to the file.
+    pub fn append_leaves(&mut self, leaves: &[[u8; HASH_LEN]]) -> Result<(), anyhow::Error> {
+        if leaves.is_empty() {
+            return Ok(());
+        }
+
+        let start = self.next_index;
+        let next_index = start + leaves.len() as u64;
+        anyhow::ensure!(
+            next_index <= 1 << self.height,
+            "{} leaves don't fit in the Merkle tree",
+            leaves.len()
+        );
+
+        let offset = node_offset(self.height, 0, start) as usize;
+        let len = leaves.len() * HASH_LEN;
+        self.mmap[offset..offset + len].copy_from_slice(&leaves.concat());
+        self.mmap.flush_range(offset, len)?;
+        self.update_nodes(start, next_index)?;
+
+        // Commit.
+        self.mmap[16..24].copy_from_slice(&next_index.to_le_bytes());
+        self.mmap.flush_range(0, HEADER_SIZE as usize)?;
+        self.next_index = next_index;
+
+        Ok(())
+    }
+
+    /// Inserts a pair of leaves, the same way as
+    /// [`MerkleTree::insert`](light_merkle_tree::MerkleTree::insert).
+    pub fn insert(
+        &mut self,
+        leaf1: [u8; HASH_LEN],
+        leaf2: [u8; HASH_LEN],
+    ) -> Result<(), anyhow::Error> {
+        self.append_leaves(&[leaf1, leaf2])
+    }
+
+    /// Returns the `filled_subtrees` frontier, as kept by
+    /// [`MerkleTree`](light_merkle_tree::MerkleTree) of the same height.
+    pub fn filled_subtrees(&self) -> Vec<[u8; HASH_LEN]> {
+        (0..self.height)
+            .map(|level| {
+                if level == 0 || self.next_index == 0 {
+                    self.zero_bytes[level]
+                } else {
+                    // The last left node on the path of the last leaf.
+                    self.node(level, ((self.next_index - 1) >> level) & !1)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns siblings of the leaf under the given index, starting from the
+    /// bottom. Reads only one node on each level.
+    pub fn proof(&self, index: u64) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+        anyhow::ensure!(
+            index < self.next_index,
+            "leaf {} doesn't exist, next index is {}",
+            index,
+            self.next_index
+        );
+
+        Ok((0..self.height)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect())
+    }
+}
@@ -0,0 +1,439 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use light_merkle_tree::{
+    config::MerkleTreeConfig, hasher::Hasher, try_hash_to_root, MerkleTree, HASH_LEN, MAX_HEIGHT,
+    MERKLE_TREE_HISTORY_SIZE,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS tree (
+    id INTEGER PRIMARY KEY CHECK (id = 0),
+    height INTEGER NOT NULL,
+    next_index INTEGER NOT NULL,
+    current_root_index INTEGER NOT NULL,
+    filled_subtrees BLOB NOT NULL,
+    roots BLOB NOT NULL
+);
+CREATE TABLE IF NOT EXISTS leaves (
+    idx INTEGER PRIMARY KEY,
+    hash BLOB NOT NULL
+);
+CREATE TABLE IF NOT EXISTS nodes (
+    level INTEGER NOT NULL,
+    idx INTEGER NOT NULL,
+    hash BLOB NOT NULL,
+    PRIMARY KEY (level, idx)
+);
+CREATE TABLE IF NOT EXISTS roots (
+    seq INTEGER PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    root BLOB NOT NULL
+);
+";
+
+fn to_hash(blob: Vec<u8>) -> Result<[u8; HASH_LEN], anyhow::Error> {
+    <[u8; HASH_LEN]>::try_from(blob)
+        .map_err(|blob| anyhow::anyhow!("expected {HASH_LEN} bytes, got {}", blob.len()))
+}
+
+fn to_hashes<const N: usize>(blob: Vec<u8>) -> Result<[[u8; HASH_LEN]; N], anyhow::Error> {
+    anyhow::ensure!(
+        blob.len() == N * HASH_LEN,
+        "expected {} bytes, got {}",
+        N * HASH_LEN,
+        blob.len()
+    );
+
+    let mut hashes = [[0u8; HASH_LEN]; N];
+    for (hash, chunk) in hashes.iter_mut().zip(blob.chunks_exact(HASH_LEN)) {
+        hash.copy_from_slice(chunk);
+    }
+    Ok(hashes)
+}
+
+/// Off-chain replica of a [`MerkleTree`](light_merkle_tree::MerkleTree)
+/// persisted in SQLite.
+///
+/// Besides the state of the `MerkleTree` struct itself, the store keeps all
+/// leaves, interior nodes and the full history of roots, so it can produce
+/// proofs and be resumed after a restart.
+///
+/// Every insert is tagged with the slot it happened in, so the replica can be
+/// rolled back when a fork gets abandoned.
+pub struct SqliteStore<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    conn: Connection,
+    merkle_tree: MerkleTree<H, C>,
+}
+
+impl<H, C> SqliteStore<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Opens the store in the given file, creating it if it doesn't exist.
+    /// An existing store is resumed from its last persisted state.
+    pub fn open<P: AsRef<Path>>(path: P, height: usize) -> Result<Self, anyhow::Error> {
+        Self::from_connection(Connection::open(path)?, height)
+    }
+
+    /// Opens a store kept only in memory.
+    pub fn open_in_memory(height: usize) -> Result<Self, anyhow::Error> {
+        Self::from_connection(Connection::open_in_memory()?, height)
+    }
+
+    fn from_connection(mut conn: Connection, height: usize) -> Result<Self, anyhow::Error> {
+        anyhow::ensure!(
+            (1..=MAX_HEIGHT).contains(&height),
+            "height has to be between 1 and {MAX_HEIGHT}"
+        );
+
+        conn.execute_batch(SCHEMA)?;
+
+        let merkle_tree = match Self::load(&conn)? {
+            Some(merkle_tree) => {
+                anyhow::ensure!(
+                    merkle_tree.height == height as u64,
+                    "store contains a Merkle tree of height {}, expected {}",
+                    merkle_tree.height,
+                    height
+                );
+                merkle_tree
+            }
+            None => {
+                let merkle_tree = MerkleTree::new(height);
+                let tx = conn.transaction()?;
+                Self::save(&tx, &merkle_tree)?;
+                tx.execute(
+                    "INSERT INTO roots (seq, slot, root) VALUES (0, 0, ?1)",
+                    params![merkle_tree.last_root()],
+                )?;
+                tx.commit()?;
+                merkle_tree
+            }
+        };
+
+        Ok(SqliteStore { conn, merkle_tree })
+    }
+
+    /// Loads the state of the `MerkleTree` struct.
+    fn load(conn: &Connection) -> Result<Option<MerkleTree<H, C>>, anyhow::Error> {
+        let row = conn
+            .query_row(
+                "SELECT height, next_index, current_root_index, filled_subtrees, roots
+                 FROM tree WHERE id = 0",
+                [],
+                |row| {
+                    Ok((
+                        row.get::<_, u64>(0)?,
+                        row.get::<_, u64>(1)?,
+                        row.get::<_, u64>(2)?,
+                        row.get::<_, Vec<u8>>(3)?,
+                        row.get::<_, Vec<u8>>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+
+        row.map(
+            |(height, next_index, current_root_index, filled_subtrees, roots)| {
+                let mut merkle_tree = MerkleTree::new(height as usize);
+                merkle_tree.next_index = next_index;
+                merkle_tree.current_root_index = current_root_index;
+                merkle_tree.filled_subtrees = to_hashes::<MAX_HEIGHT>(filled_subtrees)?;
+                merkle_tree.roots = to_hashes::<MERKLE_TREE_HISTORY_SIZE>(roots)?;
+                Ok(merkle_tree)
+            },
+        )
+        .transpose()
+    }
+
+    /// Saves the state of the `MerkleTree` struct.
+    fn save(tx: &Transaction, merkle_tree: &MerkleTree<H, C>) -> Result<(), anyhow::Error> {
+        tx.execute(
+            "INSERT OR REPLACE INTO tree
+             (id, height, next_index, current_root_index, filled_subtrees, roots)
+             VALUES (0, ?1, ?2, ?3, ?4, ?5)",
+            params![
+                merkle_tree.height,
+                merkle_tree.next_index,
+                merkle_tree.current_root_index,
+                merkle_tree.filled_subtrees.concat(),
+                merkle_tree.roots.concat(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn node_in(
+        conn: &Connection,
+        level: usize,
+        index: u64,
+    ) -> Result<[u8; HASH_LEN], anyhow::Error> {
+        let hash = if level == 0 {
+            conn.query_row(
+                "SELECT hash FROM leaves WHERE idx = ?1",
+                params![index],
+                |row| row.get::<_, Vec<u8>>(0),
+            )
+        } else {
+            conn.query_row(
+                "SELECT hash FROM nodes WHERE level = ?1 AND idx = ?2",
+                params![level, index],
+                |row| row.get::<_, Vec<u8>>(0),
+            )
+        }
+        .optional()?;
+
+        match hash {
+            Some(hash) => to_hash(hash),
+            None => Ok(C::ZERO_BYTES[level]),
+        }
+    }
+
+    /// Returns the node under the given level and index. Level 0 contains
+    /// leaves. Nodes of empty subtrees are taken from `C::ZERO_BYTES`.
+    pub fn node(&self, level: usize, index: u64) -> Result<[u8; HASH_LEN], anyhow::Error> {
+        Self::node_in(&self.conn, level, index)
+    }
+
+    /// Brings the in-memory state back in sync with the database after a
+    /// failed operation.
+    fn reload_on_error<T>(&mut self, result: Result<T, anyhow::Error>) -> Result<T, anyhow::Error> {
+        if result.is_err() {
+            if let Some(merkle_tree) = Self::load(&self.conn)? {
+                self.merkle_tree = merkle_tree;
+            }
+        }
+        result
+    }
+
+    /// Inserts a pair of leaves in the given slot, the same way as
+    /// [`MerkleTree::insert`](light_merkle_tree::MerkleTree::insert), and
+    /// persists them with updated nodes and root.
+    pub fn insert(
+        &mut self,
+        slot: u64,
+        leaf1: [u8; HASH_LEN],
+        leaf2: [u8; HASH_LEN],
+    ) -> Result<(), anyhow::Error> {
+        let result = self.try_insert(slot, leaf1, leaf2);
+        self.reload_on_error(result)
+    }
+
+    fn try_insert(
+        &mut self,
+        slot: u64,
+        leaf1: [u8; HASH_LEN],
+        leaf2: [u8; HASH_LEN],
+    ) -> Result<(), anyhow::Error> {
+        let height = self.merkle_tree.height as usize;
+        let index = self.merkle_tree.next_index;
+        anyhow::ensure!(
+            index < 1 << height,
+            "Merkle tree of height {} is full",
+            height
+        );
+        if let Some(last_slot) = self.last_slot()? {
+            anyhow::ensure!(
+                slot >= last_slot,
+                "slot {} is older than the last indexed slot {}",
+                slot,
+                last_slot
+            );
+        }
+
+        let tx = self.conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO leaves (idx, hash) VALUES (?1, ?2), (?3, ?4)",
+            params![index, leaf1, index + 1, leaf2],
+        )?;
+
+        let insert_node = |level: usize, index: u64, hash: &[u8; HASH_LEN]| {
+            tx.execute(
+                "INSERT OR REPLACE INTO nodes (level, idx, hash) VALUES (?1, ?2, ?3)",
+                params![level, index, hash],
+            )
+        };
+        let node = try_hash_to_root::<H, C, anyhow::Error>(
+            height,
+            1,
+            index / 2,
+            C::hash_pair::<H>(&leaf1, &leaf2),
+            |level, index, node| {
+                insert_node(level, index, node)?;
+                Self::node_in(&tx, level, index ^ 1)
+            },
+        )?;
+        insert_node(height, 0, &node)?;
+
+        self.merkle_tree.insert(leaf1, leaf2);
+        let root = C::mix_root::<H>(&node, self.merkle_tree.next_index);
+        anyhow::ensure!(
+            self.merkle_tree.last_root() == root,
+            "root of the stored nodes doesn't match the root of the Merkle tree"
+        );
+
+        Self::save(&tx, &self.merkle_tree)?;
+        tx.execute(
+            "INSERT INTO roots (seq, slot, root) VALUES (?1, ?2, ?3)",
+            params![self.merkle_tree.next_index / 2, slot, root],
+        )?;
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Returns the slot of the last insert, if there was any.
+    pub fn last_slot(&self) -> Result<Option<u64>, anyhow::Error> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT slot FROM roots WHERE seq > 0 ORDER BY seq DESC LIMIT 1",
+                [],
+                |row| row.get::<_, u64>(0),
+            )
+            .optional()?)
+    }
+
+    /// Restores the state of the replica (leaves, nodes, `filled_subtrees` and
+    /// the root history) to the one after the last insert in the given slot.
+    /// Inserts from later slots are discarded.
+    pub fn rollback_to_slot(&mut self, slot: u64) -> Result<(), anyhow::Error> {
+        let result = self.try_rollback_to_slot(slot);
+        self.reload_on_error(result)
+    }
+
+    fn try_rollback_to_slot(&mut self, slot: u64) -> Result<(), anyhow::Error> {
+        let height = self.merkle_tree.height as usize;
+        let tx = self.conn.transaction()?;
+
+        // Number of inserts to keep.
+        let inserts: u64 = tx.query_row(
+            "SELECT COALESCE(MAX(seq), 0) FROM roots WHERE slot <= ?1",
+            params![slot],
+            |row| row.get(0),
+        )?;
+        let next_index = inserts * 2;
+        if next_index == self.merkle_tree.next_index {
+            return Ok(());
+        }
+
+        tx.execute("DELETE FROM leaves WHERE idx >= ?1", params![next_index])?;
+        tx.execute("DELETE FROM roots WHERE seq > ?1", params![inserts])?;
+        for level in 1..=height {
+            // Remove nodes which don't have any remaining leaves below.
+            let first_empty = (next_index + (1 << level) - 1) >> level;
+            tx.execute(
+                "DELETE FROM nodes WHERE level = ?1 AND idx >= ?2",
+                params![level, first_empty],
+            )?;
+        }
+
+        // Recompute the path of the last remaining leaf, which might contain
+        // leaves from removed inserts.
+        let mut filled_subtrees = MerkleTree::<H, C>::new(height).filled_subtrees;
+        if next_index > 0 {
+            for level in 1..=height {
+                let index = (next_index - 1) >> level;
+                let left = Self::node_in(&tx, level - 1, index * 2)?;
+                let right = Self::node_in(&tx, level - 1, index * 2 + 1)?;
+                tx.execute(
+                    "INSERT OR REPLACE INTO nodes (level, idx, hash) VALUES (?1, ?2, ?3)",
+                    params![level, index, C::hash_pair::<H>(&left, &right)],
+                )?;
+            }
+
+            // `MerkleTree::insert` stores a node in `filled_subtrees` every
+            // time it's a left child, so every level holds the last left node
+            // on the path of the last leaf.
+            for (level, filled_subtree) in filled_subtrees[..height].iter_mut().enumerate().skip(1)
+            {
+                let index = ((next_index - 1) >> level) & !1;
+                *filled_subtree = Self::node_in(&tx, level, index)?;
+            }
+        }
+
+        // The root of `n`-th insert is stored in the ring buffer under
+        // `n % MERKLE_TREE_HISTORY_SIZE`.
+        let mut roots = [[0u8; HASH_LEN]; MERKLE_TREE_HISTORY_SIZE];
+        {
+            let mut stmt = tx.prepare("SELECT seq, root FROM roots ORDER BY seq")?;
+            let mut rows = stmt.query([])?;
+            while let Some(row) = rows.next()? {
+                let seq: u64 = row.get(0)?;
+                roots[seq as usize % MERKLE_TREE_HISTORY_SIZE] = to_hash(row.get(1)?)?;
+            }
+        }
+
+        self.merkle_tree.next_index = next_index;
+        self.merkle_tree.current_root_index = inserts % MERKLE_TREE_HISTORY_SIZE as u64;
+        self.merkle_tree.filled_subtrees = filled_subtrees;
+        self.merkle_tree.roots = roots;
+        if next_index > 0 {
+            anyhow::ensure!(
+                self.merkle_tree.last_root()
+                    == C::mix_root::<H>(&Self::node_in(&tx, height, 0)?, next_index),
+                "root of the stored nodes doesn't match the root of the Merkle tree"
+            );
+        }
+
+        Self::save(&tx, &self.merkle_tree)?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Replica of the `MerkleTree` struct.
+    pub fn merkle_tree(&self) -> &MerkleTree<H, C> {
+        &self.merkle_tree
+    }
+
+    pub fn next_index(&self) -> u64 {
+        self.merkle_tree.next_index
+    }
+
+    pub fn last_root(&self) -> [u8; HASH_LEN] {
+        self.merkle_tree.last_root()
+    }
+
+    /// Returns all roots of the Merkle tree, starting with the root of the
+    /// empty tree.
+    pub fn root_history(&self) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+        let mut stmt = self.conn.prepare("SELECT root FROM roots ORDER BY seq")?;
+        let roots = stmt
+            .query_map([], |row| row.get::<_, Vec<u8>>(0))?
+            .map(|root| to_hash(root?))
+            .collect();
+        roots
+    }
+
+    pub fn leaf(&self, index: u64) -> Result<Option<[u8; HASH_LEN]>, anyhow::Error> {
+        if index >= self.merkle_tree.next_index {
+            return Ok(None);
+        }
+        self.node(0, index).map(Some)
+    }
+
+    /// Returns siblings of the leaf under the given index, starting from the
+    /// bottom.
+    pub fn proof(&self, index: u64) -> Result<Vec<[u8; HASH_LEN]>, anyhow::Error> {
+        anyhow::ensure!(
+            index < self.merkle_tree.next_index,
+            "leaf {} doesn't exist, next index is {}",
+            index,
+            self.merkle_tree.next_index
+        );
+
+        (0..self.merkle_tree.height as usize)
+            .map(|level| self.node(level, (index >> level) ^ 1))
+            .collect()
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{Seek, SeekFrom, Write},
+};
+
+use light_merkle_indexer::{
+    mmap::{node_offset, HEADER_SIZE, MAX_MMAP_HEIGHT},
+    MmapStore, SqliteStore,
+};
+use light_merkle_tree::{
+    config::{DepositContractMerkleTreeConfig, Sha256MerkleTreeConfig},
+    hasher::{Hasher, Sha256},
+    MerkleTree, MERKLE_TREE_HISTORY_SIZE,
+};
+
+type Store = SqliteStore<Sha256, Sha256MerkleTreeConfig>;
+
+fn verify_proof(
+    merkle_tree: &mut MerkleTree<Sha256, Sha256MerkleTreeConfig>,
+    leaf: [u8; 32],
+    index: u64,
+    proof: &[[u8; 32]],
+) -> bool {
+    let mut node = leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        node = if (index >> level).is_multiple_of(2) {
+            merkle_tree.hash(node, *sibling)
+        } else {
+            merkle_tree.hash(*sibling, node)
+        };
+    }
+    node == merkle_tree.last_root()
+}
+
+#[test]
+fn test_sqlite_store_reload() {
+    let path = std::env::temp_dir().join(format!("light-merkle-indexer-{}.db", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(4);
+    let mut roots = vec![merkle_tree.last_root()];
+
+    {
+        let mut store = Store::open(&path, 4).unwrap();
+        for i in 0..3u8 {
+            merkle_tree.insert([2 * i; 32], [2 * i + 1; 32]);
+            store
+                .insert(i as u64, [2 * i; 32], [2 * i + 1; 32])
+                .unwrap();
+            roots.push(merkle_tree.last_root());
+        }
+        assert_eq!(store.last_root(), merkle_tree.last_root());
+    }
+
+    // Resume from the persisted state.
+    let mut store = Store::open(&path, 4).unwrap();
+    assert_eq!(store.next_index(), 6);
+    assert_eq!(store.last_root(), merkle_tree.last_root());
+    assert_eq!(
+        store.merkle_tree().filled_subtrees,
+        merkle_tree.filled_subtrees
+    );
+    assert_eq!(store.merkle_tree().roots, merkle_tree.roots);
+
+    for i in 3..8u8 {
+        merkle_tree.insert([2 * i; 32], [2 * i + 1; 32]);
+        store
+            .insert(i as u64, [2 * i; 32], [2 * i + 1; 32])
+            .unwrap();
+        roots.push(merkle_tree.last_root());
+    }
+    assert_eq!(store.last_root(), merkle_tree.last_root());
+    assert_eq!(store.root_history().unwrap(), roots);
+
+    // The tree is full.
+    assert!(store.insert(8, [0; 32], [0; 32]).is_err());
+    assert_eq!(store.next_index(), 16);
+
+    for index in 0..16 {
+        let leaf = store.leaf(index).unwrap().unwrap();
+        assert_eq!(leaf, [index as u8; 32]);
+        let proof = store.proof(index).unwrap();
+        assert!(verify_proof(&mut merkle_tree, leaf, index, &proof));
+    }
+
+    drop(store);
+    // Height of an existing store can't be changed.
+    assert!(Store::open(&path, 5).is_err());
+
+    fs::remove_file(&path).unwrap();
+}
+
+/// Snapshot of the `MerkleTree` struct state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    next_index: u64,
+    current_root_index: u64,
+    filled_subtrees: [[u8; 32]; 18],
+    roots: [[u8; 32]; MERKLE_TREE_HISTORY_SIZE],
+}
+
+impl Snapshot {
+    fn new(merkle_tree: &MerkleTree<Sha256, Sha256MerkleTreeConfig>) -> Self {
+        Snapshot {
+            next_index: merkle_tree.next_index,
+            current_root_index: merkle_tree.current_root_index,
+            filled_subtrees: merkle_tree.filled_subtrees,
+            roots: merkle_tree.roots,
+        }
+    }
+}
+
+fn leaf(i: u64) -> ([u8; 32], [u8; 32]) {
+    let mut leaf1 = [0u8; 32];
+    leaf1[..8].copy_from_slice(&(2 * i).to_le_bytes());
+    let mut leaf2 = [0u8; 32];
+    leaf2[..8].copy_from_slice(&(2 * i + 1).to_le_bytes());
+    (leaf1, leaf2)
+}
+
+#[test]
+fn test_sqlite_store_rollback_to_slot() {
+    let mut store = Store::open_in_memory(6).unwrap();
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(6);
+
+    // Two inserts per slot, starting at slot 10, with the root history
+    // wrapping around.
+    let mut snapshots = vec![(9, Snapshot::new(&merkle_tree))];
+    for i in 0..25 {
+        let slot = 10 + i / 2;
+        let (leaf1, leaf2) = leaf(i);
+        store.insert(slot, leaf1, leaf2).unwrap();
+        merkle_tree.insert(leaf1, leaf2);
+        if i % 2 == 1 || i == 24 {
+            snapshots.push((slot, Snapshot::new(&merkle_tree)));
+        }
+    }
+    assert_eq!(store.last_slot().unwrap(), Some(22));
+
+    // Older slots are rejected.
+    assert!(store.insert(21, [0; 32], [0; 32]).is_err());
+
+    // Slots without inserts keep the state of the last slot with inserts.
+    store.rollback_to_slot(100).unwrap();
+    assert_eq!(
+        Snapshot::new(store.merkle_tree()),
+        snapshots.last().unwrap().1
+    );
+
+    for (slot, snapshot) in snapshots.iter().rev() {
+        store.rollback_to_slot(*slot).unwrap();
+        assert_eq!(&Snapshot::new(store.merkle_tree()), snapshot);
+
+        let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(6);
+        for i in 0..snapshot.next_index / 2 {
+            let (leaf1, leaf2) = leaf(i);
+            merkle_tree.insert(leaf1, leaf2);
+        }
+        for index in 0..snapshot.next_index {
+            let leaf = store.leaf(index).unwrap().unwrap();
+            let proof = store.proof(index).unwrap();
+            assert!(verify_proof(&mut merkle_tree, leaf, index, &proof));
+        }
+        assert_eq!(store.leaf(snapshot.next_index).unwrap(), None);
+    }
+
+    // Roll back in the middle of the history and index a different fork.
+    let mut store = Store::open_in_memory(6).unwrap();
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(6);
+    for i in 0..25 {
+        let (leaf1, leaf2) = leaf(i);
+        store.insert(10 + i / 2, leaf1, leaf2).unwrap();
+        if i < 6 {
+            merkle_tree.insert(leaf1, leaf2);
+        }
+    }
+    store.rollback_to_slot(12).unwrap();
+    assert_eq!(store.next_index(), 12);
+    for i in 6..30 {
+        store.insert(13, [i as u8; 32], [0; 32]).unwrap();
+        merkle_tree.insert([i as u8; 32], [0; 32]);
+        assert_eq!(
+            Snapshot::new(store.merkle_tree()),
+            Snapshot::new(&merkle_tree)
+        );
+    }
+    for index in 0..60 {
+        let leaf = store.leaf(index).unwrap().unwrap();
+        let proof = store.proof(index).unwrap();
+        assert!(verify_proof(&mut merkle_tree, leaf, index, &proof));
+    }
+}
+
+#[test]
+fn test_mmap_store() {
+    let path = std::env::temp_dir().join(format!("light-merkle-mmap-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(5);
+    {
+        let mut store = MmapStore::<Sha256, Sha256MerkleTreeConfig>::open(&path, 5).unwrap();
+        assert_eq!(store.root(), merkle_tree.last_root());
+        for i in 0..5 {
+            let (leaf1, leaf2) = leaf(i);
+            store.insert(leaf1, leaf2).unwrap();
+            merkle_tree.insert(leaf1, leaf2);
+
+            assert_eq!(store.root(), merkle_tree.last_root());
+            assert_eq!(store.filled_subtrees(), &merkle_tree.filled_subtrees[..5]);
+        }
+    }
+
+    // Simulate a crash in the middle of the next append: the leaf and the
+    // nodes on its path are written, but the header is not updated.
+    {
+        let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+        for level in 0..=5 {
+            file.seek(SeekFrom::Start(node_offset(5, level, 10 >> level)))
+                .unwrap();
+            file.write_all(&[0xff; 32]).unwrap();
+        }
+    }
+
+    let mut store = MmapStore::<Sha256, Sha256MerkleTreeConfig>::open(&path, 5).unwrap();
+    assert_eq!(store.next_index(), 10);
+    assert_eq!(store.root(), merkle_tree.last_root());
+    assert_eq!(store.leaf(10), None);
+
+    // Resume appending.
+    for i in 5..16 {
+        let (leaf1, leaf2) = leaf(i);
+        store.insert(leaf1, leaf2).unwrap();
+        merkle_tree.insert(leaf1, leaf2);
+        assert_eq!(store.root(), merkle_tree.last_root());
+        assert_eq!(store.filled_subtrees(), &merkle_tree.filled_subtrees[..5]);
+    }
+    assert!(store.insert([0; 32], [0; 32]).is_err());
+
+    for index in 0..32 {
+        let leaf = store.leaf(index).unwrap();
+        let proof = store.proof(index).unwrap();
+        assert!(verify_proof(&mut merkle_tree, leaf, index, &proof));
+    }
+
+    drop(store);
+    assert!(MmapStore::<Sha256, Sha256MerkleTreeConfig>::open(&path, 6).is_err());
+
+    // Corrupt the number of leaves in the header.
+    {
+        let mut file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.seek(SeekFrom::Start(16)).unwrap();
+        file.write_all(&33u64.to_le_bytes()).unwrap();
+    }
+    assert!(MmapStore::<Sha256, Sha256MerkleTreeConfig>::open(&path, 5).is_err());
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_mmap_store_mix_in_length() {
+    type Config = DepositContractMerkleTreeConfig;
+
+    let path = std::env::temp_dir().join(format!("light-merkle-mmap-mixed-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(4);
+    let mut store = MmapStore::<Sha256, Config>::open(&path, 4).unwrap();
+    assert_eq!(store.root(), merkle_tree.last_root());
+    for i in 0..8 {
+        let (leaf1, leaf2) = leaf(i);
+        store.insert(leaf1, leaf2).unwrap();
+        merkle_tree.insert(leaf1, leaf2);
+        assert_eq!(store.root(), merkle_tree.last_root());
+    }
+
+    drop(store);
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_node_offset() {
+    // Layout of a tree with a billion leaves.
+    assert_eq!(node_offset(30, 0, 0), HEADER_SIZE);
+    assert_eq!(node_offset(30, 0, 99), HEADER_SIZE + 99 * 32);
+    assert_eq!(node_offset(30, 1, 0), HEADER_SIZE + (1 << 30) * 32);
+    assert_eq!(
+        node_offset(30, 2, 1),
+        HEADER_SIZE + ((1 << 30) + (1 << 29) + 1) * 32
+    );
+    assert_eq!(node_offset(30, 30, 0), HEADER_SIZE + ((1 << 31) - 2) * 32);
+    // Size of the file.
+    assert_eq!(node_offset(30, 30, 1), HEADER_SIZE + ((1 << 31) - 1) * 32);
+
+    // Files of the highest trees fit in ext4.
+    assert!(node_offset(MAX_MMAP_HEIGHT, MAX_MMAP_HEIGHT, 1) <= 1 << 44);
+    assert!(node_offset(MAX_MMAP_HEIGHT + 1, MAX_MMAP_HEIGHT + 1, 1) > 1 << 44);
+}
+
+// Creates a 64 GiB sparse file, which needs a filesystem supporting sparse
+// files and enough space on tmpfs.
+#[test]
+#[ignore]
+fn test_mmap_store_billion_leaves() {
+    let path = std::env::temp_dir().join(format!("light-merkle-mmap-30-{}", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    // The file is sparse, only pages with nodes on paths of appended leaves
+    // are allocated.
+    let mut store = MmapStore::<Sha256, Sha256MerkleTreeConfig>::open(&path, 30).unwrap();
+    let leaves: Vec<[u8; 32]> = (0..100u8).map(|i| [i; 32]).collect();
+    store.append_leaves(&leaves).unwrap();
+    assert_eq!(store.next_index(), 100);
+
+    for index in [0, 1, 57, 99] {
+        let proof = store.proof(index).unwrap();
+        assert_eq!(proof.len(), 30);
+
+        let mut node = leaves[index as usize];
+        for (level, sibling) in proof.iter().enumerate() {
+            node = if (index >> level).is_multiple_of(2) {
+                Sha256::hashv(&[&node, sibling])
+            } else {
+                Sha256::hashv(&[sibling, &node])
+            };
+        }
+        assert_eq!(node, store.root());
+    }
+
+    drop(store);
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,144 @@
+use crate::hasher::{Hash, Hasher, Sha256};
+
+/// Size of a serialized block header.
+pub const BLOCK_HEADER_LEN: usize = 80;
+
+/// Double SHA-256, used by Bitcoin for transaction IDs, block hashes and
+/// Merkle trees.
+pub fn sha256d(vals: &[&[u8]]) -> Hash {
+    Sha256::hash(&Sha256::hashv(vals))
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    sha256d(&[left, right])
+}
+
+/// Computes the next level of the tree. The last node of a level with an odd
+/// number of nodes is paired with itself.
+fn next_level(nodes: &[Hash]) -> Vec<Hash> {
+    nodes
+        .chunks(2)
+        .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+        .collect()
+}
+
+/// Computes the Merkle root of a block with the given transaction IDs (in
+/// the internal byte order, as hashed, not as displayed). Returns `None` for
+/// an empty list.
+///
+/// Because of the duplication of odd nodes, lists ending with repeated
+/// transaction IDs may have the same root as shorter ones (CVE-2012-2459).
+/// Blocks with duplicate transactions have to be rejected separately.
+pub fn merkle_root(txids: &[Hash]) -> Option<Hash> {
+    if txids.is_empty() {
+        return None;
+    }
+
+    let mut nodes = txids.to_vec();
+    while nodes.len() > 1 {
+        nodes = next_level(&nodes);
+    }
+    Some(nodes[0])
+}
+
+/// Returns the Merkle branch of the transaction under the given index, i.e.
+/// siblings starting from the bottom.
+pub fn merkle_proof(txids: &[Hash], index: usize) -> Option<Vec<Hash>> {
+    if index >= txids.len() {
+        return None;
+    }
+
+    let mut proof = vec![];
+    let mut nodes = txids.to_vec();
+    let mut index = index;
+    while nodes.len() > 1 {
+        proof.push(*nodes.get(index ^ 1).unwrap_or(&nodes[index]));
+        nodes = next_level(&nodes);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+/// Returns the depth of the tree of a block with the given number of
+/// transactions, i.e. the length of its Merkle branches.
+pub fn tree_depth(tx_count: usize) -> usize {
+    tx_count.next_power_of_two().trailing_zeros() as usize
+}
+
+/// Verifies the Merkle branch of the transaction under the given index
+/// against the Merkle root of a block with `tx_count` transactions.
+///
+/// The number of transactions binds the length of the branch, so an inner
+/// node (or a 64-byte transaction, which hashes like one) can't be proven
+/// as a transaction with a shorter branch. It has to come from a trusted
+/// source, e.g. the block itself.
+pub fn verify_proof(txid: Hash, index: usize, tx_count: usize, proof: &[Hash], root: Hash) -> bool {
+    if index >= tx_count || proof.len() != tree_depth(tx_count) {
+        return false;
+    }
+
+    let (mut node, mut index, mut width) = (txid, index, tx_count);
+    for sibling in proof {
+        node = if index.is_multiple_of(2) {
+            // The last node of an odd level is paired with itself.
+            if index + 1 == width && *sibling != node {
+                return false;
+            }
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+        index /= 2;
+        width = width.div_ceil(2);
+    }
+    node == root
+}
+
+/// Bitcoin block header.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct BlockHeader {
+    pub version: i32,
+    pub prev_block: Hash,
+    pub merkle_root: Hash,
+    pub time: u32,
+    pub bits: u32,
+    pub nonce: u32,
+}
+
+impl BlockHeader {
+    /// Parses a serialized block header.
+    pub fn from_bytes(bytes: &[u8; BLOCK_HEADER_LEN]) -> Self {
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+
+        BlockHeader {
+            version: u32_at(0) as i32,
+            prev_block: bytes[4..36].try_into().unwrap(),
+            merkle_root: bytes[36..68].try_into().unwrap(),
+            time: u32_at(68),
+            bits: u32_at(72),
+            nonce: u32_at(76),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; BLOCK_HEADER_LEN] {
+        let mut bytes = [0u8; BLOCK_HEADER_LEN];
+        bytes[0..4].copy_from_slice(&self.version.to_le_bytes());
+        bytes[4..36].copy_from_slice(&self.prev_block);
+        bytes[36..68].copy_from_slice(&self.merkle_root);
+        bytes[68..72].copy_from_slice(&self.time.to_le_bytes());
+        bytes[72..76].copy_from_slice(&self.bits.to_le_bytes());
+        bytes[76..80].copy_from_slice(&self.nonce.to_le_bytes());
+        bytes
+    }
+
+    /// Hash of the block, in the internal byte order.
+    pub fn hash(&self) -> Hash {
+        sha256d(&[&self.to_bytes()])
+    }
+
+    /// Checks whether the block commits to the given transaction IDs.
+    pub fn verify_txids(&self, txids: &[Hash]) -> bool {
+        merkle_root(txids) == Some(self.merkle_root)
+    }
+}
@@ -1,26 +1,190 @@
 #[cfg(feature = "solana")]
 use anchor_lang::prelude::*;
 
-use crate::constants::ZeroBytes;
+use crate::{
+    constants::ZeroBytes,
+    hasher::{Hash, Hasher},
+    mixed_root, HASH_LEN,
+};
 
 pub trait MerkleTreeConfig {
+    /// Value of an empty leaf.
+    const EMPTY_LEAF: [u8; HASH_LEN];
+    /// Hashes of empty subtrees on each level, starting with `EMPTY_LEAF`.
+    /// They can be derived from the empty leaf with
+    /// [`zero_bytes`](crate::constants::zero_bytes).
     const ZERO_BYTES: ZeroBytes;
+    /// Whether each pair of nodes is sorted before hashing, as in
+    /// OpenZeppelin `MerkleProof`. Proofs of such trees can be verified
+    /// without knowing the index of the leaf.
+    const SORTED_PAIRS: bool = false;
+    /// Whether roots commit to the number of leaves, as in the Ethereum
+    /// deposit contract. See [`mix_in_length`](crate::mixed_root::mix_in_length).
+    const MIX_IN_LENGTH: bool = false;
+    /// Whether the root of an empty tree is the empty node on the level of
+    /// the root, as in SPL account compression, Semaphore and the Ethereum
+    /// deposit contract. Otherwise it's the empty node one level below, as
+    /// in Tornado Cash. Roots of non-empty trees don't depend on it.
+    const FULL_HEIGHT_EMPTY_ROOT: bool = false;
     #[cfg(feature = "solana")]
     const PROGRAM_ID: Pubkey;
+
+    /// Hashes a pair of nodes into their parent.
+    fn hash_pair<H: Hasher>(left: &[u8; HASH_LEN], right: &[u8; HASH_LEN]) -> Hash {
+        if Self::SORTED_PAIRS && left > right {
+            H::hashv(&[right, left])
+        } else {
+            H::hashv(&[left, right])
+        }
+    }
+
+    /// Returns the level of zero bytes holding the root node of an empty tree
+    /// with the given height.
+    fn empty_root_level(height: usize) -> usize {
+        if Self::FULL_HEIGHT_EMPTY_ROOT {
+            height
+        } else {
+            height - 1
+        }
+    }
+
+    /// Returns the root recorded for a tree with the given root node and
+    /// number of leaves.
+    fn mix_root<H: Hasher>(root: &[u8; HASH_LEN], next_index: u64) -> Hash {
+        if Self::MIX_IN_LENGTH {
+            mixed_root::mix_in_length::<H>(root, next_index)
+        } else {
+            *root
+        }
+    }
 }
 
-#[cfg(not(feature = "solana"))]
 mod configs {
     use super::*;
 
     use crate::constants;
 
+    /// Built-in configs aren't tied to any program. Programs owning trees
+    /// define their own configs with their program ID.
+    #[cfg(feature = "solana")]
+    const NO_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
     pub struct Sha256MerkleTreeConfig;
 
     impl MerkleTreeConfig for Sha256MerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = constants::sha256::ZERO_BYTES[0];
         const ZERO_BYTES: ZeroBytes = constants::sha256::ZERO_BYTES;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct KeccakMerkleTreeConfig;
+
+    impl MerkleTreeConfig for KeccakMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = constants::keccak::ZERO_BYTES[0];
+        const ZERO_BYTES: ZeroBytes = constants::keccak::ZERO_BYTES;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct Blake3MerkleTreeConfig;
+
+    impl MerkleTreeConfig for Blake3MerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = constants::blake3::ZERO_BYTES[0];
+        const ZERO_BYTES: ZeroBytes = constants::blake3::ZERO_BYTES;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct PoseidonMerkleTreeConfig;
+
+    impl MerkleTreeConfig for PoseidonMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = constants::poseidon::ZERO_BYTES[0];
+        const ZERO_BYTES: ZeroBytes = constants::poseidon::ZERO_BYTES;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    // Configs with an all-zero empty leaf, compatible with e.g. the Ethereum
+    // deposit contract (SHA-256), SPL account compression (Keccak) and
+    // Semaphore (Poseidon).
+    pub struct Sha256ZeroMerkleTreeConfig;
+
+    impl MerkleTreeConfig for Sha256ZeroMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        const ZERO_BYTES: ZeroBytes = constants::sha256_zero::ZERO_BYTES;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct KeccakZeroMerkleTreeConfig;
+
+    impl MerkleTreeConfig for KeccakZeroMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        const ZERO_BYTES: ZeroBytes = constants::keccak_zero::ZERO_BYTES;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct Blake3ZeroMerkleTreeConfig;
+
+    impl MerkleTreeConfig for Blake3ZeroMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        const ZERO_BYTES: ZeroBytes = constants::blake3_zero::ZERO_BYTES;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    pub struct PoseidonZeroMerkleTreeConfig;
+
+    impl MerkleTreeConfig for PoseidonZeroMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        const ZERO_BYTES: ZeroBytes = constants::poseidon_zero::ZERO_BYTES;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    /// Config of Tornado Cash, with MiMC sponge hashes and the empty leaf
+    /// `keccak256("tornado") % FIELD_SIZE`.
+    pub struct TornadoMerkleTreeConfig;
+
+    impl MerkleTreeConfig for TornadoMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = constants::mimc_sponge_tornado::ZERO_BYTES[0];
+        const ZERO_BYTES: ZeroBytes = constants::mimc_sponge_tornado::ZERO_BYTES;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    /// Config of the Ethereum deposit contract, with an all-zero empty leaf
+    /// and roots mixed with the number of leaves.
+    pub struct DepositContractMerkleTreeConfig;
+
+    impl MerkleTreeConfig for DepositContractMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        const ZERO_BYTES: ZeroBytes = constants::sha256_zero::ZERO_BYTES;
+        const MIX_IN_LENGTH: bool = true;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
+    }
+
+    /// Config with sorted pairs and an all-zero empty leaf, compatible with
+    /// OpenZeppelin `MerkleProof`.
+    pub struct KeccakSortedMerkleTreeConfig;
+
+    impl MerkleTreeConfig for KeccakSortedMerkleTreeConfig {
+        const EMPTY_LEAF: [u8; HASH_LEN] = [0; HASH_LEN];
+        // Sorting doesn't change hashes of two equal nodes.
+        const ZERO_BYTES: ZeroBytes = constants::keccak_zero::ZERO_BYTES;
+        const SORTED_PAIRS: bool = true;
+        const FULL_HEIGHT_EMPTY_ROOT: bool = true;
+        #[cfg(feature = "solana")]
+        const PROGRAM_ID: Pubkey = NO_PROGRAM_ID;
     }
 }
 
-#[cfg(not(feature = "solana"))]
 pub use configs::*;
@@ -0,0 +1,100 @@
+// This file is generated by xtask. Do not edit it manually.
+
+use super::ZeroBytes;
+pub const ZERO_BYTES: ZeroBytes = [
+    [
+        176u8, 67u8, 157u8, 238u8, 156u8, 235u8, 142u8, 188u8, 153u8, 131u8, 245u8, 250u8, 146u8,
+        249u8, 175u8, 242u8, 84u8, 203u8, 19u8, 71u8, 22u8, 169u8, 248u8, 71u8, 248u8, 140u8,
+        106u8, 156u8, 20u8, 223u8, 85u8, 64u8,
+    ],
+    [
+        135u8, 203u8, 0u8, 218u8, 68u8, 96u8, 144u8, 215u8, 220u8, 141u8, 137u8, 34u8, 191u8,
+        120u8, 30u8, 181u8, 135u8, 187u8, 14u8, 161u8, 46u8, 52u8, 107u8, 146u8, 210u8, 48u8, 46u8,
+        110u8, 116u8, 78u8, 89u8, 114u8,
+    ],
+    [
+        241u8, 163u8, 148u8, 220u8, 81u8, 193u8, 89u8, 63u8, 79u8, 252u8, 65u8, 234u8, 138u8,
+        172u8, 148u8, 215u8, 183u8, 167u8, 44u8, 124u8, 223u8, 127u8, 203u8, 116u8, 208u8, 165u8,
+        252u8, 18u8, 102u8, 209u8, 242u8, 239u8,
+    ],
+    [
+        178u8, 244u8, 68u8, 110u8, 204u8, 102u8, 95u8, 19u8, 39u8, 7u8, 151u8, 209u8, 109u8, 107u8,
+        103u8, 120u8, 84u8, 196u8, 51u8, 200u8, 30u8, 51u8, 162u8, 36u8, 206u8, 136u8, 214u8,
+        111u8, 1u8, 21u8, 59u8, 227u8,
+    ],
+    [
+        219u8, 109u8, 114u8, 67u8, 77u8, 175u8, 71u8, 150u8, 218u8, 197u8, 88u8, 173u8, 203u8,
+        159u8, 47u8, 207u8, 72u8, 88u8, 181u8, 232u8, 194u8, 77u8, 180u8, 2u8, 102u8, 249u8, 199u8,
+        19u8, 28u8, 148u8, 53u8, 160u8,
+    ],
+    [
+        79u8, 211u8, 171u8, 208u8, 201u8, 24u8, 16u8, 102u8, 128u8, 166u8, 85u8, 196u8, 114u8,
+        61u8, 229u8, 101u8, 72u8, 171u8, 41u8, 136u8, 59u8, 128u8, 73u8, 220u8, 133u8, 208u8,
+        235u8, 195u8, 64u8, 143u8, 11u8, 160u8,
+    ],
+    [
+        70u8, 236u8, 52u8, 255u8, 137u8, 187u8, 90u8, 237u8, 148u8, 255u8, 218u8, 226u8, 138u8,
+        89u8, 36u8, 253u8, 29u8, 172u8, 6u8, 179u8, 126u8, 19u8, 238u8, 49u8, 147u8, 2u8, 86u8,
+        52u8, 140u8, 12u8, 180u8, 27u8,
+    ],
+    [
+        118u8, 224u8, 62u8, 188u8, 54u8, 57u8, 246u8, 168u8, 142u8, 177u8, 156u8, 14u8, 103u8,
+        243u8, 187u8, 248u8, 55u8, 214u8, 39u8, 187u8, 39u8, 210u8, 208u8, 38u8, 219u8, 126u8,
+        47u8, 231u8, 191u8, 172u8, 203u8, 7u8,
+    ],
+    [
+        231u8, 244u8, 71u8, 211u8, 23u8, 137u8, 130u8, 199u8, 14u8, 102u8, 55u8, 73u8, 120u8,
+        128u8, 179u8, 245u8, 135u8, 185u8, 226u8, 136u8, 228u8, 126u8, 85u8, 95u8, 173u8, 116u8,
+        116u8, 185u8, 121u8, 200u8, 253u8, 101u8,
+    ],
+    [
+        116u8, 121u8, 249u8, 101u8, 50u8, 198u8, 71u8, 41u8, 79u8, 64u8, 82u8, 132u8, 188u8, 179u8,
+        189u8, 105u8, 16u8, 15u8, 212u8, 231u8, 165u8, 240u8, 196u8, 250u8, 136u8, 62u8, 43u8,
+        51u8, 177u8, 24u8, 248u8, 43u8,
+    ],
+    [
+        79u8, 242u8, 192u8, 233u8, 143u8, 107u8, 229u8, 145u8, 35u8, 70u8, 133u8, 145u8, 129u8,
+        237u8, 73u8, 226u8, 138u8, 89u8, 107u8, 192u8, 153u8, 109u8, 213u8, 166u8, 40u8, 176u8,
+        126u8, 157u8, 30u8, 170u8, 169u8, 178u8,
+    ],
+    [
+        221u8, 59u8, 88u8, 17u8, 254u8, 232u8, 21u8, 190u8, 217u8, 139u8, 111u8, 179u8, 67u8, 24u8,
+        74u8, 3u8, 122u8, 210u8, 169u8, 95u8, 193u8, 127u8, 63u8, 40u8, 203u8, 221u8, 46u8, 16u8,
+        25u8, 131u8, 178u8, 32u8,
+    ],
+    [
+        9u8, 61u8, 88u8, 57u8, 117u8, 162u8, 170u8, 184u8, 84u8, 46u8, 103u8, 125u8, 156u8, 2u8,
+        32u8, 66u8, 90u8, 173u8, 176u8, 93u8, 179u8, 73u8, 10u8, 214u8, 29u8, 14u8, 53u8, 127u8,
+        70u8, 23u8, 198u8, 212u8,
+    ],
+    [
+        103u8, 21u8, 165u8, 52u8, 9u8, 84u8, 150u8, 209u8, 157u8, 21u8, 60u8, 226u8, 112u8, 184u8,
+        199u8, 32u8, 162u8, 168u8, 123u8, 110u8, 111u8, 208u8, 211u8, 243u8, 7u8, 11u8, 240u8,
+        154u8, 12u8, 254u8, 13u8, 43u8,
+    ],
+    [
+        90u8, 249u8, 79u8, 99u8, 61u8, 56u8, 229u8, 99u8, 49u8, 135u8, 215u8, 104u8, 187u8, 69u8,
+        163u8, 236u8, 184u8, 125u8, 234u8, 30u8, 239u8, 100u8, 97u8, 109u8, 20u8, 66u8, 9u8, 243u8,
+        117u8, 110u8, 148u8, 232u8,
+    ],
+    [
+        249u8, 220u8, 158u8, 247u8, 202u8, 49u8, 47u8, 95u8, 90u8, 85u8, 235u8, 255u8, 108u8,
+        165u8, 174u8, 147u8, 106u8, 78u8, 247u8, 15u8, 48u8, 166u8, 4u8, 162u8, 191u8, 171u8,
+        219u8, 131u8, 181u8, 138u8, 116u8, 126u8,
+    ],
+    [
+        48u8, 83u8, 147u8, 153u8, 74u8, 79u8, 176u8, 69u8, 70u8, 237u8, 114u8, 64u8, 45u8, 224u8,
+        88u8, 181u8, 229u8, 157u8, 184u8, 70u8, 194u8, 50u8, 19u8, 246u8, 208u8, 82u8, 212u8,
+        225u8, 8u8, 22u8, 75u8, 158u8,
+    ],
+    [
+        66u8, 196u8, 183u8, 75u8, 86u8, 159u8, 145u8, 136u8, 112u8, 73u8, 53u8, 84u8, 54u8, 168u8,
+        163u8, 211u8, 26u8, 189u8, 132u8, 236u8, 230u8, 175u8, 174u8, 49u8, 221u8, 216u8, 45u8,
+        234u8, 204u8, 29u8, 29u8, 223u8,
+    ],
+    [
+        142u8, 71u8, 50u8, 77u8, 251u8, 132u8, 179u8, 43u8, 84u8, 98u8, 193u8, 105u8, 255u8, 84u8,
+        227u8, 195u8, 46u8, 51u8, 139u8, 241u8, 73u8, 220u8, 77u8, 233u8, 233u8, 80u8, 110u8,
+        179u8, 176u8, 136u8, 159u8, 74u8,
+    ],
+];
@@ -0,0 +1,99 @@
+// This file is generated by xtask. Do not edit it manually.
+
+use super::ZeroBytes;
+pub const ZERO_BYTES: ZeroBytes = [
+    [
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    ],
+    [
+        77u8, 0u8, 105u8, 118u8, 99u8, 106u8, 134u8, 150u8, 217u8, 9u8, 166u8, 48u8, 164u8, 8u8,
+        26u8, 173u8, 77u8, 124u8, 80u8, 248u8, 26u8, 253u8, 238u8, 4u8, 2u8, 11u8, 240u8, 80u8,
+        134u8, 171u8, 106u8, 85u8,
+    ],
+    [
+        73u8, 138u8, 142u8, 124u8, 2u8, 188u8, 44u8, 122u8, 252u8, 89u8, 139u8, 9u8, 122u8, 208u8,
+        101u8, 131u8, 110u8, 168u8, 78u8, 14u8, 223u8, 43u8, 240u8, 137u8, 84u8, 16u8, 150u8,
+        135u8, 17u8, 214u8, 181u8, 78u8,
+    ],
+    [
+        6u8, 37u8, 60u8, 82u8, 237u8, 133u8, 54u8, 228u8, 176u8, 119u8, 87u8, 214u8, 121u8, 197u8,
+        71u8, 253u8, 178u8, 5u8, 17u8, 129u8, 169u8, 203u8, 209u8, 227u8, 81u8, 107u8, 252u8,
+        113u8, 116u8, 41u8, 54u8, 247u8,
+    ],
+    [
+        49u8, 180u8, 113u8, 178u8, 123u8, 34u8, 181u8, 123u8, 26u8, 200u8, 44u8, 158u8, 213u8,
+        55u8, 35u8, 29u8, 83u8, 250u8, 240u8, 23u8, 251u8, 224u8, 201u8, 3u8, 201u8, 102u8, 143u8,
+        71u8, 220u8, 65u8, 81u8, 225u8,
+    ],
+    [
+        98u8, 43u8, 111u8, 101u8, 15u8, 63u8, 234u8, 192u8, 121u8, 137u8, 83u8, 245u8, 141u8,
+        237u8, 41u8, 109u8, 169u8, 162u8, 226u8, 152u8, 203u8, 53u8, 102u8, 200u8, 222u8, 45u8,
+        39u8, 217u8, 101u8, 142u8, 11u8, 140u8,
+    ],
+    [
+        219u8, 194u8, 142u8, 186u8, 110u8, 25u8, 115u8, 110u8, 12u8, 92u8, 167u8, 144u8, 253u8,
+        128u8, 254u8, 156u8, 234u8, 24u8, 86u8, 246u8, 144u8, 121u8, 37u8, 86u8, 43u8, 11u8, 94u8,
+        59u8, 45u8, 121u8, 56u8, 47u8,
+    ],
+    [
+        32u8, 6u8, 141u8, 47u8, 75u8, 192u8, 12u8, 129u8, 130u8, 84u8, 181u8, 176u8, 73u8, 151u8,
+        43u8, 230u8, 182u8, 238u8, 92u8, 170u8, 72u8, 123u8, 98u8, 17u8, 228u8, 18u8, 157u8, 159u8,
+        212u8, 234u8, 7u8, 197u8,
+    ],
+    [
+        246u8, 255u8, 209u8, 92u8, 108u8, 71u8, 179u8, 1u8, 59u8, 182u8, 76u8, 169u8, 214u8, 250u8,
+        99u8, 211u8, 217u8, 228u8, 9u8, 18u8, 129u8, 221u8, 251u8, 67u8, 145u8, 196u8, 160u8, 33u8,
+        212u8, 76u8, 109u8, 149u8,
+    ],
+    [
+        224u8, 91u8, 190u8, 199u8, 33u8, 237u8, 244u8, 229u8, 30u8, 13u8, 147u8, 60u8, 142u8, 40u8,
+        38u8, 252u8, 48u8, 90u8, 201u8, 91u8, 125u8, 122u8, 185u8, 149u8, 49u8, 231u8, 64u8, 86u8,
+        222u8, 1u8, 142u8, 155u8,
+    ],
+    [
+        108u8, 134u8, 226u8, 9u8, 90u8, 27u8, 192u8, 131u8, 94u8, 226u8, 199u8, 22u8, 193u8, 210u8,
+        98u8, 11u8, 21u8, 25u8, 6u8, 252u8, 235u8, 153u8, 153u8, 57u8, 3u8, 70u8, 185u8, 241u8,
+        157u8, 142u8, 178u8, 253u8,
+    ],
+    [
+        30u8, 134u8, 15u8, 228u8, 93u8, 195u8, 81u8, 235u8, 168u8, 6u8, 55u8, 226u8, 85u8, 214u8,
+        185u8, 82u8, 183u8, 69u8, 97u8, 255u8, 225u8, 245u8, 50u8, 240u8, 122u8, 227u8, 31u8, 58u8,
+        130u8, 231u8, 26u8, 217u8,
+    ],
+    [
+        85u8, 63u8, 218u8, 107u8, 79u8, 247u8, 151u8, 146u8, 153u8, 185u8, 20u8, 42u8, 255u8,
+        185u8, 52u8, 236u8, 170u8, 190u8, 86u8, 243u8, 187u8, 226u8, 252u8, 145u8, 81u8, 127u8,
+        167u8, 107u8, 238u8, 249u8, 158u8, 76u8,
+    ],
+    [
+        170u8, 215u8, 178u8, 99u8, 182u8, 152u8, 77u8, 103u8, 14u8, 38u8, 134u8, 247u8, 202u8,
+        203u8, 58u8, 99u8, 193u8, 93u8, 106u8, 214u8, 134u8, 140u8, 60u8, 135u8, 1u8, 26u8, 114u8,
+        121u8, 125u8, 107u8, 8u8, 253u8,
+    ],
+    [
+        128u8, 223u8, 64u8, 3u8, 148u8, 186u8, 55u8, 136u8, 155u8, 48u8, 59u8, 15u8, 100u8, 255u8,
+        157u8, 94u8, 84u8, 118u8, 159u8, 152u8, 20u8, 186u8, 140u8, 212u8, 196u8, 156u8, 7u8, 79u8,
+        223u8, 153u8, 38u8, 35u8,
+    ],
+    [
+        62u8, 37u8, 170u8, 54u8, 110u8, 137u8, 52u8, 86u8, 113u8, 38u8, 243u8, 133u8, 250u8, 143u8,
+        24u8, 26u8, 25u8, 11u8, 40u8, 104u8, 3u8, 209u8, 149u8, 27u8, 100u8, 51u8, 187u8, 74u8,
+        111u8, 35u8, 9u8, 168u8,
+    ],
+    [
+        184u8, 103u8, 203u8, 38u8, 98u8, 249u8, 73u8, 47u8, 118u8, 249u8, 225u8, 194u8, 4u8, 188u8,
+        180u8, 33u8, 159u8, 11u8, 16u8, 86u8, 188u8, 19u8, 96u8, 219u8, 198u8, 44u8, 53u8, 238u8,
+        41u8, 162u8, 66u8, 51u8,
+    ],
+    [
+        124u8, 233u8, 241u8, 73u8, 60u8, 13u8, 99u8, 129u8, 142u8, 55u8, 240u8, 230u8, 121u8,
+        234u8, 143u8, 99u8, 22u8, 186u8, 188u8, 168u8, 85u8, 19u8, 51u8, 62u8, 38u8, 171u8, 219u8,
+        203u8, 184u8, 195u8, 211u8, 224u8,
+    ],
+    [
+        20u8, 100u8, 238u8, 187u8, 52u8, 206u8, 250u8, 241u8, 151u8, 12u8, 25u8, 95u8, 0u8, 14u8,
+        5u8, 158u8, 136u8, 218u8, 120u8, 98u8, 89u8, 55u8, 190u8, 107u8, 23u8, 78u8, 126u8, 109u8,
+        221u8, 170u8, 158u8, 62u8,
+    ],
+];
@@ -0,0 +1,100 @@
+// This file is generated by xtask. Do not edit it manually.
+
+use super::ZeroBytes;
+pub const ZERO_BYTES: ZeroBytes = [
+    [
+        132u8, 26u8, 112u8, 113u8, 191u8, 195u8, 35u8, 123u8, 37u8, 1u8, 116u8, 110u8, 28u8, 43u8,
+        106u8, 38u8, 152u8, 153u8, 249u8, 3u8, 193u8, 234u8, 167u8, 130u8, 199u8, 175u8, 157u8,
+        221u8, 34u8, 146u8, 187u8, 83u8,
+    ],
+    [
+        227u8, 80u8, 196u8, 20u8, 231u8, 226u8, 210u8, 130u8, 152u8, 172u8, 139u8, 6u8, 87u8, 34u8,
+        88u8, 78u8, 83u8, 131u8, 243u8, 12u8, 209u8, 198u8, 226u8, 106u8, 78u8, 201u8, 1u8, 117u8,
+        137u8, 22u8, 32u8, 83u8,
+    ],
+    [
+        119u8, 29u8, 214u8, 185u8, 81u8, 213u8, 240u8, 0u8, 128u8, 111u8, 82u8, 18u8, 104u8, 226u8,
+        17u8, 212u8, 195u8, 150u8, 110u8, 50u8, 119u8, 84u8, 70u8, 246u8, 46u8, 103u8, 25u8, 179u8,
+        49u8, 9u8, 163u8, 7u8,
+    ],
+    [
+        24u8, 129u8, 99u8, 118u8, 206u8, 31u8, 195u8, 174u8, 149u8, 72u8, 43u8, 111u8, 120u8,
+        114u8, 113u8, 233u8, 160u8, 95u8, 150u8, 109u8, 222u8, 115u8, 244u8, 99u8, 13u8, 138u8,
+        76u8, 63u8, 83u8, 244u8, 75u8, 16u8,
+    ],
+    [
+        40u8, 38u8, 156u8, 145u8, 29u8, 225u8, 147u8, 123u8, 237u8, 52u8, 16u8, 37u8, 222u8, 199u8,
+        99u8, 81u8, 247u8, 116u8, 67u8, 126u8, 70u8, 19u8, 71u8, 45u8, 218u8, 218u8, 142u8, 244u8,
+        223u8, 104u8, 66u8, 84u8,
+    ],
+    [
+        0u8, 225u8, 235u8, 181u8, 52u8, 19u8, 191u8, 154u8, 43u8, 200u8, 24u8, 250u8, 82u8, 248u8,
+        138u8, 25u8, 48u8, 98u8, 69u8, 143u8, 124u8, 211u8, 35u8, 225u8, 189u8, 118u8, 202u8, 2u8,
+        215u8, 231u8, 173u8, 178u8,
+    ],
+    [
+        226u8, 183u8, 144u8, 148u8, 187u8, 24u8, 29u8, 154u8, 195u8, 145u8, 11u8, 98u8, 81u8, 87u8,
+        168u8, 41u8, 133u8, 26u8, 5u8, 25u8, 6u8, 115u8, 155u8, 149u8, 224u8, 102u8, 81u8, 218u8,
+        168u8, 38u8, 14u8, 30u8,
+    ],
+    [
+        202u8, 203u8, 42u8, 53u8, 178u8, 43u8, 13u8, 110u8, 250u8, 124u8, 153u8, 31u8, 210u8,
+        123u8, 0u8, 88u8, 233u8, 173u8, 134u8, 218u8, 216u8, 121u8, 156u8, 34u8, 147u8, 136u8,
+        225u8, 157u8, 205u8, 83u8, 234u8, 232u8,
+    ],
+    [
+        103u8, 243u8, 112u8, 248u8, 245u8, 156u8, 78u8, 246u8, 79u8, 121u8, 38u8, 17u8, 77u8,
+        227u8, 189u8, 33u8, 161u8, 53u8, 44u8, 63u8, 135u8, 85u8, 229u8, 153u8, 145u8, 192u8, 13u8,
+        68u8, 52u8, 233u8, 193u8, 111u8,
+    ],
+    [
+        35u8, 207u8, 208u8, 139u8, 84u8, 188u8, 213u8, 255u8, 78u8, 7u8, 138u8, 93u8, 245u8, 232u8,
+        56u8, 14u8, 38u8, 44u8, 179u8, 229u8, 61u8, 244u8, 229u8, 228u8, 200u8, 35u8, 152u8, 109u8,
+        6u8, 56u8, 58u8, 185u8,
+    ],
+    [
+        173u8, 179u8, 138u8, 190u8, 1u8, 143u8, 255u8, 222u8, 13u8, 213u8, 156u8, 185u8, 62u8,
+        14u8, 6u8, 211u8, 24u8, 133u8, 99u8, 209u8, 8u8, 82u8, 170u8, 113u8, 94u8, 73u8, 12u8,
+        179u8, 55u8, 107u8, 122u8, 230u8,
+    ],
+    [
+        112u8, 29u8, 160u8, 56u8, 156u8, 52u8, 120u8, 247u8, 129u8, 22u8, 177u8, 176u8, 230u8,
+        111u8, 123u8, 21u8, 163u8, 155u8, 146u8, 212u8, 195u8, 185u8, 202u8, 177u8, 59u8, 130u8,
+        20u8, 198u8, 139u8, 149u8, 57u8, 253u8,
+    ],
+    [
+        96u8, 35u8, 20u8, 68u8, 73u8, 29u8, 232u8, 208u8, 101u8, 73u8, 249u8, 66u8, 111u8, 102u8,
+        42u8, 249u8, 95u8, 123u8, 196u8, 241u8, 100u8, 238u8, 211u8, 215u8, 114u8, 94u8, 96u8,
+        213u8, 23u8, 1u8, 201u8, 241u8,
+    ],
+    [
+        247u8, 35u8, 91u8, 245u8, 130u8, 68u8, 225u8, 168u8, 216u8, 156u8, 63u8, 168u8, 155u8,
+        189u8, 8u8, 232u8, 54u8, 27u8, 217u8, 209u8, 185u8, 172u8, 43u8, 133u8, 64u8, 120u8, 140u8,
+        65u8, 68u8, 207u8, 245u8, 186u8,
+    ],
+    [
+        169u8, 15u8, 73u8, 98u8, 235u8, 6u8, 73u8, 218u8, 139u8, 168u8, 56u8, 182u8, 136u8, 37u8,
+        108u8, 151u8, 195u8, 221u8, 94u8, 104u8, 181u8, 25u8, 162u8, 86u8, 67u8, 177u8, 187u8, 0u8,
+        109u8, 108u8, 63u8, 46u8,
+    ],
+    [
+        232u8, 8u8, 159u8, 241u8, 14u8, 51u8, 234u8, 36u8, 161u8, 132u8, 51u8, 141u8, 139u8, 62u8,
+        110u8, 206u8, 142u8, 0u8, 253u8, 8u8, 17u8, 14u8, 105u8, 245u8, 11u8, 222u8, 94u8, 221u8,
+        149u8, 252u8, 89u8, 73u8,
+    ],
+    [
+        155u8, 28u8, 239u8, 183u8, 173u8, 79u8, 8u8, 163u8, 140u8, 48u8, 119u8, 51u8, 41u8, 81u8,
+        194u8, 48u8, 149u8, 32u8, 21u8, 121u8, 199u8, 196u8, 142u8, 95u8, 21u8, 222u8, 156u8,
+        133u8, 152u8, 228u8, 115u8, 17u8,
+    ],
+    [
+        227u8, 46u8, 49u8, 72u8, 173u8, 43u8, 115u8, 129u8, 168u8, 57u8, 189u8, 202u8, 5u8, 53u8,
+        36u8, 166u8, 202u8, 119u8, 165u8, 74u8, 103u8, 23u8, 148u8, 244u8, 195u8, 75u8, 51u8,
+        197u8, 33u8, 193u8, 86u8, 160u8,
+    ],
+    [
+        215u8, 98u8, 155u8, 186u8, 115u8, 137u8, 4u8, 29u8, 167u8, 16u8, 108u8, 151u8, 6u8, 247u8,
+        191u8, 198u8, 60u8, 40u8, 76u8, 178u8, 109u8, 151u8, 89u8, 219u8, 142u8, 193u8, 109u8,
+        230u8, 149u8, 28u8, 169u8, 123u8,
+    ],
+];
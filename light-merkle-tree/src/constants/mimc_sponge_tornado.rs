@@ -0,0 +1,100 @@
+// This file is generated by xtask. Do not edit it manually.
+
+use super::ZeroBytes;
+pub const ZERO_BYTES: ZeroBytes = [
+    [
+        47u8, 229u8, 76u8, 96u8, 211u8, 172u8, 171u8, 243u8, 52u8, 58u8, 53u8, 182u8, 235u8, 161u8,
+        93u8, 180u8, 130u8, 27u8, 52u8, 15u8, 118u8, 231u8, 65u8, 226u8, 36u8, 150u8, 133u8, 237u8,
+        72u8, 153u8, 175u8, 108u8,
+    ],
+    [
+        37u8, 106u8, 97u8, 53u8, 119u8, 126u8, 238u8, 47u8, 210u8, 111u8, 84u8, 184u8, 183u8, 3u8,
+        122u8, 37u8, 67u8, 157u8, 82u8, 53u8, 202u8, 238u8, 34u8, 65u8, 84u8, 24u8, 109u8, 43u8,
+        138u8, 82u8, 227u8, 29u8,
+    ],
+    [
+        17u8, 81u8, 148u8, 152u8, 149u8, 232u8, 42u8, 177u8, 153u8, 36u8, 222u8, 146u8, 196u8,
+        10u8, 61u8, 111u8, 123u8, 203u8, 96u8, 217u8, 43u8, 0u8, 80u8, 75u8, 129u8, 153u8, 97u8,
+        54u8, 131u8, 240u8, 194u8, 0u8,
+    ],
+    [
+        32u8, 18u8, 30u8, 232u8, 17u8, 72u8, 159u8, 248u8, 214u8, 31u8, 9u8, 251u8, 137u8, 227u8,
+        19u8, 241u8, 73u8, 89u8, 160u8, 242u8, 139u8, 180u8, 40u8, 162u8, 13u8, 186u8, 107u8, 11u8,
+        6u8, 139u8, 59u8, 219u8,
+    ],
+    [
+        10u8, 137u8, 202u8, 111u8, 250u8, 20u8, 204u8, 70u8, 44u8, 254u8, 219u8, 132u8, 44u8, 48u8,
+        237u8, 34u8, 26u8, 80u8, 163u8, 214u8, 191u8, 2u8, 42u8, 106u8, 87u8, 220u8, 130u8, 171u8,
+        36u8, 193u8, 87u8, 201u8,
+    ],
+    [
+        36u8, 202u8, 5u8, 194u8, 181u8, 205u8, 66u8, 232u8, 144u8, 214u8, 190u8, 148u8, 198u8,
+        141u8, 6u8, 137u8, 244u8, 242u8, 28u8, 156u8, 236u8, 156u8, 15u8, 19u8, 254u8, 65u8, 213u8,
+        102u8, 223u8, 181u8, 73u8, 89u8,
+    ],
+    [
+        28u8, 203u8, 151u8, 201u8, 50u8, 86u8, 90u8, 146u8, 198u8, 1u8, 86u8, 189u8, 186u8, 45u8,
+        8u8, 243u8, 191u8, 19u8, 119u8, 70u8, 78u8, 2u8, 92u8, 238u8, 118u8, 86u8, 121u8, 230u8,
+        4u8, 167u8, 49u8, 92u8,
+    ],
+    [
+        25u8, 21u8, 111u8, 189u8, 125u8, 26u8, 139u8, 245u8, 203u8, 168u8, 144u8, 147u8, 103u8,
+        222u8, 27u8, 98u8, 69u8, 52u8, 235u8, 171u8, 79u8, 15u8, 121u8, 224u8, 3u8, 188u8, 205u8,
+        209u8, 177u8, 130u8, 189u8, 180u8,
+    ],
+    [
+        38u8, 26u8, 248u8, 193u8, 240u8, 145u8, 46u8, 70u8, 87u8, 68u8, 100u8, 20u8, 9u8, 246u8,
+        34u8, 212u8, 102u8, 195u8, 146u8, 10u8, 198u8, 229u8, 255u8, 55u8, 227u8, 102u8, 4u8,
+        203u8, 17u8, 223u8, 255u8, 128u8,
+    ],
+    [
+        0u8, 88u8, 69u8, 151u8, 36u8, 255u8, 108u8, 165u8, 161u8, 101u8, 47u8, 203u8, 195u8, 232u8,
+        43u8, 147u8, 137u8, 92u8, 240u8, 142u8, 151u8, 91u8, 25u8, 190u8, 171u8, 63u8, 84u8, 194u8,
+        23u8, 209u8, 192u8, 7u8,
+    ],
+    [
+        31u8, 4u8, 239u8, 32u8, 222u8, 228u8, 141u8, 57u8, 152u8, 77u8, 142u8, 171u8, 231u8, 104u8,
+        167u8, 14u8, 175u8, 166u8, 49u8, 10u8, 210u8, 8u8, 73u8, 212u8, 87u8, 60u8, 60u8, 64u8,
+        194u8, 173u8, 30u8, 48u8,
+    ],
+    [
+        27u8, 234u8, 61u8, 236u8, 93u8, 171u8, 81u8, 86u8, 124u8, 231u8, 226u8, 0u8, 163u8, 15u8,
+        123u8, 166u8, 212u8, 39u8, 106u8, 234u8, 165u8, 62u8, 38u8, 134u8, 249u8, 98u8, 164u8,
+        108u8, 102u8, 213u8, 17u8, 229u8,
+    ],
+    [
+        14u8, 224u8, 249u8, 65u8, 226u8, 218u8, 75u8, 158u8, 49u8, 195u8, 202u8, 151u8, 164u8,
+        13u8, 143u8, 169u8, 206u8, 104u8, 217u8, 124u8, 8u8, 65u8, 119u8, 7u8, 27u8, 60u8, 180u8,
+        108u8, 211u8, 55u8, 47u8, 15u8,
+    ],
+    [
+        28u8, 169u8, 80u8, 62u8, 137u8, 53u8, 136u8, 69u8, 1u8, 187u8, 175u8, 32u8, 190u8, 20u8,
+        235u8, 76u8, 70u8, 184u8, 151u8, 114u8, 201u8, 123u8, 150u8, 227u8, 178u8, 235u8, 243u8,
+        163u8, 106u8, 148u8, 139u8, 189u8,
+    ],
+    [
+        19u8, 58u8, 128u8, 227u8, 6u8, 151u8, 205u8, 85u8, 216u8, 247u8, 212u8, 176u8, 150u8, 91u8,
+        123u8, 226u8, 64u8, 87u8, 186u8, 93u8, 195u8, 218u8, 137u8, 142u8, 226u8, 24u8, 114u8,
+        50u8, 68u8, 108u8, 177u8, 8u8,
+    ],
+    [
+        19u8, 230u8, 216u8, 252u8, 136u8, 131u8, 158u8, 215u8, 110u8, 24u8, 44u8, 42u8, 119u8,
+        154u8, 245u8, 178u8, 192u8, 218u8, 157u8, 209u8, 140u8, 144u8, 66u8, 122u8, 100u8, 79u8,
+        126u8, 20u8, 138u8, 98u8, 83u8, 182u8,
+    ],
+    [
+        30u8, 177u8, 107u8, 5u8, 122u8, 71u8, 127u8, 75u8, 200u8, 245u8, 114u8, 234u8, 107u8,
+        238u8, 57u8, 86u8, 16u8, 152u8, 247u8, 143u8, 21u8, 191u8, 179u8, 105u8, 157u8, 203u8,
+        183u8, 189u8, 141u8, 182u8, 24u8, 84u8,
+    ],
+    [
+        13u8, 162u8, 203u8, 22u8, 161u8, 206u8, 170u8, 191u8, 28u8, 22u8, 184u8, 56u8, 247u8,
+        169u8, 227u8, 242u8, 163u8, 163u8, 8u8, 141u8, 158u8, 10u8, 109u8, 235u8, 170u8, 116u8,
+        129u8, 20u8, 98u8, 6u8, 150u8, 234u8,
+    ],
+    [
+        36u8, 163u8, 179u8, 216u8, 34u8, 66u8, 11u8, 20u8, 181u8, 216u8, 203u8, 108u8, 40u8, 165u8,
+        116u8, 240u8, 30u8, 152u8, 234u8, 158u8, 148u8, 5u8, 81u8, 210u8, 235u8, 215u8, 92u8,
+        238u8, 18u8, 100u8, 159u8, 157u8,
+    ],
+];
@@ -1,4 +1,46 @@
+use crate::{config::MerkleTreeConfig, hasher::Hasher, HASH_LEN};
+
+pub mod blake3;
+pub mod blake3_zero;
+pub mod keccak;
+pub mod keccak_zero;
+pub mod mimc_sponge_tornado;
 pub mod poseidon;
+pub mod poseidon_zero;
 pub mod sha256;
+pub mod sha256_zero;
 
 pub type ZeroBytes = [[u8; 32]; 19];
+
+/// Computes zero bytes for the given empty leaf. The first level is the
+/// empty leaf itself and every next level is a hash of two copies of the
+/// previous one.
+///
+/// Useful for deriving zero bytes lazily, when they are not available as
+/// constants (which can be generated with `cargo xtask generate-zero-bytes
+/// --empty-leaf`).
+pub fn zero_bytes<H: Hasher>(empty_leaf: [u8; HASH_LEN]) -> ZeroBytes {
+    let mut zero_bytes = [empty_leaf; 19];
+    for i in 1..zero_bytes.len() {
+        zero_bytes[i] = H::hashv(&[&zero_bytes[i - 1], &zero_bytes[i - 1]]);
+    }
+
+    zero_bytes
+}
+
+/// Returns zero bytes of the given config with at least `height + 1` levels.
+/// Levels above `C::ZERO_BYTES` are derived from the last one, for trees
+/// higher than [`MAX_HEIGHT`](crate::MAX_HEIGHT).
+pub fn extended_zero_bytes<H, C>(height: usize) -> Vec<[u8; HASH_LEN]>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    let mut zero_bytes = C::ZERO_BYTES.to_vec();
+    while zero_bytes.len() <= height {
+        let prev = zero_bytes[zero_bytes.len() - 1];
+        zero_bytes.push(H::hashv(&[&prev, &prev]));
+    }
+
+    zero_bytes
+}
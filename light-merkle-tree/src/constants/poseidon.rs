@@ -1,80 +1,100 @@
-use super::ZeroBytes;
+// This file is generated by xtask. Do not edit it manually.
 
+use super::ZeroBytes;
 pub const ZERO_BYTES: ZeroBytes = [
     [
-        40, 66, 58, 227, 48, 224, 249, 227, 188, 18, 133, 168, 156, 214, 220, 144, 244, 144, 67,
-        82, 76, 6, 135, 78, 64, 186, 52, 113, 234, 47, 27, 32,
+        40u8, 66u8, 58u8, 227u8, 48u8, 224u8, 249u8, 227u8, 188u8, 18u8, 133u8, 168u8, 156u8,
+        214u8, 220u8, 144u8, 244u8, 144u8, 67u8, 82u8, 76u8, 6u8, 135u8, 78u8, 64u8, 186u8, 52u8,
+        113u8, 234u8, 47u8, 27u8, 32u8,
     ],
     [
-        227, 42, 164, 149, 188, 70, 170, 8, 197, 44, 134, 162, 211, 186, 50, 238, 97, 71, 25, 130,
-        77, 70, 37, 128, 172, 154, 54, 111, 93, 193, 105, 27,
+        227u8, 42u8, 164u8, 149u8, 188u8, 70u8, 170u8, 8u8, 197u8, 44u8, 134u8, 162u8, 211u8,
+        186u8, 50u8, 238u8, 97u8, 71u8, 25u8, 130u8, 77u8, 70u8, 37u8, 128u8, 172u8, 154u8, 54u8,
+        111u8, 93u8, 193u8, 105u8, 27u8,
     ],
     [
-        25, 241, 255, 33, 65, 214, 48, 229, 38, 116, 134, 103, 44, 146, 163, 214, 31, 238, 148,
-        206, 34, 137, 144, 221, 184, 11, 5, 213, 10, 188, 143, 18,
+        25u8, 241u8, 255u8, 33u8, 65u8, 214u8, 48u8, 229u8, 38u8, 116u8, 134u8, 103u8, 44u8, 146u8,
+        163u8, 214u8, 31u8, 238u8, 148u8, 206u8, 34u8, 137u8, 144u8, 221u8, 184u8, 11u8, 5u8,
+        213u8, 10u8, 188u8, 143u8, 18u8,
     ],
     [
-        211, 61, 251, 33, 128, 34, 4, 100, 229, 47, 99, 121, 109, 204, 224, 90, 200, 149, 219, 20,
-        48, 206, 210, 177, 161, 66, 44, 10, 169, 56, 248, 8,
+        211u8, 61u8, 251u8, 33u8, 128u8, 34u8, 4u8, 100u8, 229u8, 47u8, 99u8, 121u8, 109u8, 204u8,
+        224u8, 90u8, 200u8, 149u8, 219u8, 20u8, 48u8, 206u8, 210u8, 177u8, 161u8, 66u8, 44u8, 10u8,
+        169u8, 56u8, 248u8, 8u8,
     ],
     [
-        200, 15, 65, 80, 151, 74, 72, 69, 229, 131, 25, 215, 86, 36, 195, 74, 67, 59, 117, 179, 51,
-        60, 181, 13, 242, 192, 228, 228, 189, 238, 70, 8,
+        200u8, 15u8, 65u8, 80u8, 151u8, 74u8, 72u8, 69u8, 229u8, 131u8, 25u8, 215u8, 86u8, 36u8,
+        195u8, 74u8, 67u8, 59u8, 117u8, 179u8, 51u8, 60u8, 181u8, 13u8, 242u8, 192u8, 228u8, 228u8,
+        189u8, 238u8, 70u8, 8u8,
     ],
     [
-        171, 62, 122, 81, 181, 197, 22, 238, 224, 40, 154, 231, 127, 202, 201, 169, 196, 109, 244,
-        175, 117, 101, 23, 67, 103, 57, 127, 200, 37, 43, 111, 7,
+        171u8, 62u8, 122u8, 81u8, 181u8, 197u8, 22u8, 238u8, 224u8, 40u8, 154u8, 231u8, 127u8,
+        202u8, 201u8, 169u8, 196u8, 109u8, 244u8, 175u8, 117u8, 101u8, 23u8, 67u8, 103u8, 57u8,
+        127u8, 200u8, 37u8, 43u8, 111u8, 7u8,
     ],
     [
-        59, 78, 126, 104, 199, 143, 213, 10, 2, 158, 64, 78, 153, 25, 107, 190, 32, 122, 123, 211,
-        116, 179, 175, 172, 70, 54, 175, 59, 201, 120, 64, 44,
+        59u8, 78u8, 126u8, 104u8, 199u8, 143u8, 213u8, 10u8, 2u8, 158u8, 64u8, 78u8, 153u8, 25u8,
+        107u8, 190u8, 32u8, 122u8, 123u8, 211u8, 116u8, 179u8, 175u8, 172u8, 70u8, 54u8, 175u8,
+        59u8, 201u8, 120u8, 64u8, 44u8,
     ],
     [
-        110, 91, 92, 81, 205, 89, 122, 223, 55, 163, 42, 227, 109, 54, 38, 22, 110, 217, 29, 148,
-        107, 99, 128, 106, 146, 47, 239, 41, 55, 157, 155, 22,
+        110u8, 91u8, 92u8, 81u8, 205u8, 89u8, 122u8, 223u8, 55u8, 163u8, 42u8, 227u8, 109u8, 54u8,
+        38u8, 22u8, 110u8, 217u8, 29u8, 148u8, 107u8, 99u8, 128u8, 106u8, 146u8, 47u8, 239u8, 41u8,
+        55u8, 157u8, 155u8, 22u8,
     ],
     [
-        18, 231, 42, 5, 245, 159, 211, 227, 239, 89, 35, 142, 223, 69, 166, 224, 14, 114, 128, 14,
-        123, 123, 215, 2, 241, 185, 191, 60, 252, 61, 146, 12,
+        18u8, 231u8, 42u8, 5u8, 245u8, 159u8, 211u8, 227u8, 239u8, 89u8, 35u8, 142u8, 223u8, 69u8,
+        166u8, 224u8, 14u8, 114u8, 128u8, 14u8, 123u8, 123u8, 215u8, 2u8, 241u8, 185u8, 191u8,
+        60u8, 252u8, 61u8, 146u8, 12u8,
     ],
     [
-        231, 0, 84, 227, 127, 64, 158, 7, 171, 179, 137, 231, 92, 87, 25, 221, 156, 229, 53, 208,
-        194, 201, 12, 165, 105, 150, 41, 142, 29, 205, 136, 29,
+        231u8, 0u8, 84u8, 227u8, 127u8, 64u8, 158u8, 7u8, 171u8, 179u8, 137u8, 231u8, 92u8, 87u8,
+        25u8, 221u8, 156u8, 229u8, 53u8, 208u8, 194u8, 201u8, 12u8, 165u8, 105u8, 150u8, 41u8,
+        142u8, 29u8, 205u8, 136u8, 29u8,
     ],
     [
-        195, 2, 103, 231, 62, 207, 214, 105, 214, 210, 108, 23, 28, 151, 77, 100, 78, 194, 210, 29,
-        227, 14, 17, 242, 211, 50, 33, 194, 106, 18, 246, 45,
+        195u8, 2u8, 103u8, 231u8, 62u8, 207u8, 214u8, 105u8, 214u8, 210u8, 108u8, 23u8, 28u8,
+        151u8, 77u8, 100u8, 78u8, 194u8, 210u8, 29u8, 227u8, 14u8, 17u8, 242u8, 211u8, 50u8, 33u8,
+        194u8, 106u8, 18u8, 246u8, 45u8,
     ],
     [
-        131, 178, 24, 157, 251, 247, 103, 69, 101, 229, 194, 14, 167, 57, 158, 128, 212, 19, 140,
-        234, 69, 37, 10, 156, 249, 96, 152, 52, 97, 96, 119, 41,
+        131u8, 178u8, 24u8, 157u8, 251u8, 247u8, 103u8, 69u8, 101u8, 229u8, 194u8, 14u8, 167u8,
+        57u8, 158u8, 128u8, 212u8, 19u8, 140u8, 234u8, 69u8, 37u8, 10u8, 156u8, 249u8, 96u8, 152u8,
+        52u8, 97u8, 96u8, 119u8, 41u8,
     ],
     [
-        30, 223, 20, 181, 108, 110, 112, 102, 234, 54, 99, 29, 213, 3, 55, 225, 125, 185, 223, 234,
-        188, 108, 83, 89, 27, 3, 100, 6, 65, 107, 3, 24,
+        30u8, 223u8, 20u8, 181u8, 108u8, 110u8, 112u8, 102u8, 234u8, 54u8, 99u8, 29u8, 213u8, 3u8,
+        55u8, 225u8, 125u8, 185u8, 223u8, 234u8, 188u8, 108u8, 83u8, 89u8, 27u8, 3u8, 100u8, 6u8,
+        65u8, 107u8, 3u8, 24u8,
     ],
     [
-        167, 32, 85, 233, 205, 253, 154, 214, 236, 82, 147, 75, 252, 144, 109, 73, 63, 167, 77,
-        233, 12, 201, 150, 242, 103, 15, 158, 83, 137, 24, 170, 16,
+        167u8, 32u8, 85u8, 233u8, 205u8, 253u8, 154u8, 214u8, 236u8, 82u8, 147u8, 75u8, 252u8,
+        144u8, 109u8, 73u8, 63u8, 167u8, 77u8, 233u8, 12u8, 201u8, 150u8, 242u8, 103u8, 15u8,
+        158u8, 83u8, 137u8, 24u8, 170u8, 16u8,
     ],
     [
-        45, 98, 238, 69, 136, 141, 101, 226, 94, 209, 58, 215, 212, 14, 210, 135, 110, 96, 52, 16,
-        101, 177, 121, 109, 134, 81, 189, 146, 113, 243, 97, 42,
+        45u8, 98u8, 238u8, 69u8, 136u8, 141u8, 101u8, 226u8, 94u8, 209u8, 58u8, 215u8, 212u8, 14u8,
+        210u8, 135u8, 110u8, 96u8, 52u8, 16u8, 101u8, 177u8, 121u8, 109u8, 134u8, 81u8, 189u8,
+        146u8, 113u8, 243u8, 97u8, 42u8,
     ],
     [
-        71, 51, 251, 48, 95, 193, 94, 26, 180, 17, 124, 203, 48, 98, 55, 17, 60, 104, 186, 175,
-        213, 189, 7, 239, 92, 175, 16, 5, 220, 168, 70, 21,
+        71u8, 51u8, 251u8, 48u8, 95u8, 193u8, 94u8, 26u8, 180u8, 17u8, 124u8, 203u8, 48u8, 98u8,
+        55u8, 17u8, 60u8, 104u8, 186u8, 175u8, 213u8, 189u8, 7u8, 239u8, 92u8, 175u8, 16u8, 5u8,
+        220u8, 168u8, 70u8, 21u8,
     ],
     [
-        35, 92, 72, 197, 23, 142, 16, 200, 136, 38, 44, 255, 162, 115, 11, 1, 248, 182, 236, 78,
-        90, 24, 128, 245, 168, 17, 130, 2, 73, 51, 196, 6,
+        35u8, 92u8, 72u8, 197u8, 23u8, 142u8, 16u8, 200u8, 136u8, 38u8, 44u8, 255u8, 162u8, 115u8,
+        11u8, 1u8, 248u8, 182u8, 236u8, 78u8, 90u8, 24u8, 128u8, 245u8, 168u8, 17u8, 130u8, 2u8,
+        73u8, 51u8, 196u8, 6u8,
     ],
     [
-        89, 178, 154, 246, 236, 130, 30, 100, 27, 230, 24, 196, 8, 172, 176, 196, 197, 13, 157,
-        194, 169, 106, 207, 70, 66, 117, 69, 53, 56, 154, 78, 0,
+        89u8, 178u8, 154u8, 246u8, 236u8, 130u8, 30u8, 100u8, 27u8, 230u8, 24u8, 196u8, 8u8, 172u8,
+        176u8, 196u8, 197u8, 13u8, 157u8, 194u8, 169u8, 106u8, 207u8, 70u8, 66u8, 117u8, 69u8,
+        53u8, 56u8, 154u8, 78u8, 0u8,
     ],
     [
-        231, 174, 226, 37, 211, 160, 187, 178, 149, 82, 17, 60, 110, 116, 28, 61, 58, 145, 58, 71,
-        25, 42, 67, 46, 189, 214, 248, 234, 182, 251, 238, 34,
+        231u8, 174u8, 226u8, 37u8, 211u8, 160u8, 187u8, 178u8, 149u8, 82u8, 17u8, 60u8, 110u8,
+        116u8, 28u8, 61u8, 58u8, 145u8, 58u8, 71u8, 25u8, 42u8, 67u8, 46u8, 189u8, 214u8, 248u8,
+        234u8, 182u8, 251u8, 238u8, 34u8,
     ],
 ];
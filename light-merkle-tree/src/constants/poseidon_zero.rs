@@ -0,0 +1,99 @@
+// This file is generated by xtask. Do not edit it manually.
+
+use super::ZeroBytes;
+pub const ZERO_BYTES: ZeroBytes = [
+    [
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+    ],
+    [
+        100u8, 72u8, 182u8, 70u8, 132u8, 238u8, 57u8, 168u8, 35u8, 213u8, 254u8, 95u8, 213u8, 36u8,
+        49u8, 220u8, 129u8, 228u8, 129u8, 123u8, 242u8, 195u8, 234u8, 60u8, 171u8, 158u8, 35u8,
+        158u8, 251u8, 245u8, 152u8, 32u8,
+    ],
+    [
+        225u8, 241u8, 177u8, 96u8, 68u8, 119u8, 164u8, 103u8, 240u8, 141u8, 198u8, 157u8, 203u8,
+        68u8, 26u8, 38u8, 236u8, 167u8, 132u8, 245u8, 111u8, 26u8, 48u8, 223u8, 99u8, 34u8, 177u8,
+        205u8, 61u8, 103u8, 105u8, 16u8,
+    ],
+    [
+        56u8, 210u8, 86u8, 184u8, 178u8, 126u8, 213u8, 40u8, 213u8, 29u8, 55u8, 80u8, 234u8, 110u8,
+        124u8, 70u8, 6u8, 33u8, 247u8, 80u8, 141u8, 117u8, 61u8, 46u8, 175u8, 226u8, 126u8, 83u8,
+        49u8, 51u8, 244u8, 24u8,
+    ],
+    [
+        42u8, 149u8, 188u8, 157u8, 85u8, 151u8, 172u8, 202u8, 101u8, 130u8, 86u8, 26u8, 87u8, 40u8,
+        183u8, 241u8, 69u8, 35u8, 165u8, 59u8, 233u8, 255u8, 32u8, 99u8, 211u8, 176u8, 23u8, 203u8,
+        55u8, 216u8, 249u8, 7u8,
+    ],
+    [
+        85u8, 63u8, 24u8, 57u8, 22u8, 236u8, 92u8, 123u8, 77u8, 173u8, 178u8, 148u8, 140u8, 197u8,
+        153u8, 166u8, 7u8, 41u8, 243u8, 93u8, 76u8, 31u8, 99u8, 201u8, 245u8, 179u8, 70u8, 135u8,
+        94u8, 207u8, 148u8, 43u8,
+    ],
+    [
+        120u8, 157u8, 160u8, 46u8, 163u8, 221u8, 17u8, 29u8, 97u8, 83u8, 185u8, 81u8, 105u8, 30u8,
+        215u8, 254u8, 188u8, 225u8, 169u8, 204u8, 34u8, 125u8, 234u8, 70u8, 150u8, 69u8, 102u8,
+        166u8, 197u8, 147u8, 238u8, 45u8,
+    ],
+    [
+        157u8, 52u8, 135u8, 60u8, 190u8, 170u8, 164u8, 168u8, 127u8, 172u8, 181u8, 140u8, 168u8,
+        21u8, 5u8, 139u8, 123u8, 89u8, 57u8, 182u8, 30u8, 96u8, 207u8, 130u8, 233u8, 132u8, 43u8,
+        162u8, 229u8, 149u8, 130u8, 7u8,
+    ],
+    [
+        97u8, 204u8, 243u8, 153u8, 58u8, 190u8, 76u8, 68u8, 26u8, 33u8, 65u8, 74u8, 39u8, 46u8,
+        107u8, 97u8, 42u8, 71u8, 100u8, 69u8, 134u8, 236u8, 27u8, 80u8, 166u8, 39u8, 96u8, 143u8,
+        241u8, 229u8, 165u8, 47u8,
+    ],
+    [
+        71u8, 215u8, 252u8, 20u8, 166u8, 86u8, 33u8, 62u8, 171u8, 40u8, 226u8, 227u8, 204u8, 122u8,
+        94u8, 228u8, 102u8, 31u8, 148u8, 158u8, 56u8, 128u8, 183u8, 236u8, 33u8, 253u8, 216u8,
+        208u8, 118u8, 67u8, 136u8, 14u8,
+    ],
+    [
+        242u8, 10u8, 25u8, 218u8, 229u8, 117u8, 97u8, 222u8, 51u8, 53u8, 113u8, 87u8, 249u8, 146u8,
+        88u8, 249u8, 105u8, 180u8, 46u8, 165u8, 209u8, 122u8, 113u8, 40u8, 30u8, 79u8, 73u8, 114u8,
+        218u8, 1u8, 114u8, 27u8,
+    ],
+    [
+        54u8, 118u8, 125u8, 206u8, 250u8, 107u8, 188u8, 190u8, 181u8, 8u8, 8u8, 101u8, 228u8,
+        225u8, 230u8, 166u8, 25u8, 152u8, 36u8, 1u8, 178u8, 192u8, 0u8, 82u8, 56u8, 54u8, 94u8,
+        114u8, 34u8, 136u8, 141u8, 31u8,
+    ],
+    [
+        90u8, 248u8, 181u8, 113u8, 4u8, 154u8, 135u8, 208u8, 168u8, 136u8, 207u8, 42u8, 161u8,
+        176u8, 98u8, 97u8, 251u8, 252u8, 140u8, 186u8, 137u8, 21u8, 112u8, 185u8, 175u8, 75u8,
+        145u8, 108u8, 246u8, 130u8, 93u8, 44u8,
+    ],
+    [
+        208u8, 191u8, 191u8, 224u8, 112u8, 242u8, 88u8, 100u8, 100u8, 244u8, 19u8, 161u8, 170u8,
+        196u8, 245u8, 78u8, 19u8, 161u8, 63u8, 223u8, 90u8, 127u8, 149u8, 32u8, 184u8, 11u8, 148u8,
+        160u8, 72u8, 65u8, 197u8, 20u8,
+    ],
+    [
+        12u8, 232u8, 235u8, 244u8, 75u8, 142u8, 17u8, 22u8, 212u8, 137u8, 173u8, 140u8, 88u8, 37u8,
+        190u8, 17u8, 175u8, 185u8, 216u8, 68u8, 238u8, 192u8, 16u8, 30u8, 150u8, 111u8, 152u8,
+        47u8, 177u8, 51u8, 13u8, 25u8,
+    ],
+    [
+        146u8, 108u8, 224u8, 37u8, 147u8, 100u8, 179u8, 165u8, 10u8, 81u8, 175u8, 150u8, 101u8,
+        174u8, 103u8, 17u8, 237u8, 115u8, 173u8, 20u8, 73u8, 53u8, 23u8, 172u8, 82u8, 65u8, 112u8,
+        206u8, 169u8, 138u8, 249u8, 34u8,
+    ],
+    [
+        35u8, 115u8, 186u8, 139u8, 211u8, 83u8, 183u8, 248u8, 238u8, 204u8, 110u8, 198u8, 41u8,
+        111u8, 82u8, 90u8, 87u8, 106u8, 191u8, 114u8, 141u8, 34u8, 111u8, 159u8, 11u8, 136u8,
+        229u8, 108u8, 155u8, 124u8, 124u8, 42u8,
+    ],
+    [
+        146u8, 185u8, 54u8, 63u8, 100u8, 221u8, 117u8, 77u8, 149u8, 139u8, 152u8, 194u8, 201u8,
+        67u8, 0u8, 71u8, 252u8, 63u8, 70u8, 77u8, 193u8, 249u8, 122u8, 198u8, 193u8, 142u8, 105u8,
+        88u8, 229u8, 134u8, 129u8, 46u8,
+    ],
+    [
+        15u8, 241u8, 31u8, 28u8, 157u8, 36u8, 70u8, 53u8, 39u8, 146u8, 115u8, 100u8, 173u8, 110u8,
+        239u8, 138u8, 148u8, 174u8, 13u8, 5u8, 207u8, 200u8, 226u8, 73u8, 171u8, 78u8, 154u8, 30u8,
+        87u8, 197u8, 87u8, 15u8,
+    ],
+];
@@ -0,0 +1,217 @@
+use std::{io, marker::PhantomData, mem};
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    append_to_filled_subtrees, config::MerkleTreeConfig, hasher::Hasher, MerkleTree, DATA_LEN,
+    HASH_LEN, MAX_HEIGHT, MERKLE_TREE_HISTORY_SIZE,
+};
+
+/// Frontier of a [`MerkleTree`](MerkleTree), which is enough to append
+/// leaves and get the current root, without the root history.
+///
+/// It can be serialized with Borsh, in the same layout as the corresponding
+/// fields of `MerkleTree`, or with [`to_bytes`](Frontier::to_bytes), which
+/// skips unused levels. Both forms are validated when deserializing.
+#[derive(AnchorSerialize)]
+pub struct Frontier<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Height of the Merkle tree.
+    pub height: u64,
+    /// Subtree hashes.
+    pub filled_subtrees: [[u8; HASH_LEN]; MAX_HEIGHT],
+    /// Next index to insert a leaf.
+    pub next_index: u64,
+    /// The current root, as recorded by `MerkleTree`. It can't be derived
+    /// from `filled_subtrees` when the last insert went into a right subtree.
+    pub root: [u8; HASH_LEN],
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> Frontier<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Create a frontier of an empty Merkle tree with the given height.
+    pub fn new(height: usize) -> Self {
+        Self::from(&MerkleTree::<H, C>::new(height))
+    }
+
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        self.root
+    }
+
+    /// Appends a pair of leaves, the same way as
+    /// [`MerkleTree::insert`](MerkleTree::insert).
+    pub fn append(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) {
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.next_index, 2u64.pow(self.height as u32));
+
+        let root = append_to_filled_subtrees::<H, C>(
+            &mut self.filled_subtrees[..self.height as usize],
+            1,
+            self.next_index / 2,
+            C::hash_pair::<H>(&leaf1, &leaf2),
+        );
+
+        self.next_index += 2;
+        self.root = C::mix_root::<H>(&root, self.next_index);
+    }
+
+    /// Checks whether the height and the next index describe a valid
+    /// frontier.
+    fn check(height: u64, next_index: u64) -> io::Result<()> {
+        let invalid_data = |msg| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        if height == 0 || height > MAX_HEIGHT as u64 {
+            return Err(invalid_data("invalid height of the frontier"));
+        }
+        if next_index > 1 << height || !next_index.is_multiple_of(2) {
+            return Err(invalid_data("invalid next index of the frontier"));
+        }
+
+        Ok(())
+    }
+
+    /// Size of the compact binary form of a frontier with the given height.
+    pub fn size_in_bytes(height: usize) -> usize {
+        // Height, `next_index`, the root and filled subtrees without the
+        // first one.
+        1 + mem::size_of::<u64>() + height * HASH_LEN
+    }
+
+    /// Serializes the frontier in the compact binary form:
+    ///
+    /// | Field                        | Size                      |
+    /// |------------------------------|---------------------------|
+    /// | height                       | 1                         |
+    /// | `next_index` (LE)            | 8                         |
+    /// | `root`                       | `HASH_LEN`                |
+    /// | `filled_subtrees[1..height]` | `(height - 1) * HASH_LEN` |
+    ///
+    /// The first filled subtree is never used, so it's skipped.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let height = self.height as usize;
+
+        let mut bytes = Vec::with_capacity(Self::size_in_bytes(height));
+        bytes.push(height as u8);
+        bytes.extend_from_slice(&self.next_index.to_le_bytes());
+        bytes.extend_from_slice(&self.root);
+        for filled_subtree in &self.filled_subtrees[1..height] {
+            bytes.extend_from_slice(filled_subtree);
+        }
+
+        bytes
+    }
+
+    /// Deserializes the frontier from the form produced by
+    /// [`to_bytes`](Frontier::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let invalid_data = |msg| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let height = *bytes
+            .first()
+            .ok_or_else(|| invalid_data("empty frontier"))? as usize;
+        let next_index = bytes
+            .get(1..9)
+            .map(|next_index| u64::from_le_bytes(next_index.try_into().unwrap()))
+            .ok_or_else(|| invalid_data("invalid size of the frontier"))?;
+        Self::check(height as u64, next_index)?;
+        if bytes.len() != Self::size_in_bytes(height) {
+            return Err(invalid_data("invalid size of the frontier"));
+        }
+
+        let mut frontier = Self::new(height);
+        frontier.next_index = next_index;
+        frontier.root = bytes[9..9 + HASH_LEN].try_into().unwrap();
+        for (filled_subtree, chunk) in frontier.filled_subtrees[1..height]
+            .iter_mut()
+            .zip(bytes[9 + HASH_LEN..].chunks(HASH_LEN))
+        {
+            filled_subtree.copy_from_slice(chunk);
+        }
+
+        Ok(frontier)
+    }
+}
+
+impl<H, C> AnchorDeserialize for Frontier<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let height = u64::deserialize_reader(reader)?;
+        let filled_subtrees = <[[u8; HASH_LEN]; MAX_HEIGHT]>::deserialize_reader(reader)?;
+        let next_index = u64::deserialize_reader(reader)?;
+        let root = <[u8; HASH_LEN]>::deserialize_reader(reader)?;
+        Self::check(height, next_index)?;
+
+        Ok(Frontier {
+            height,
+            filled_subtrees,
+            next_index,
+            root,
+            hasher: PhantomData,
+            config: PhantomData,
+        })
+    }
+}
+
+impl<H, C> Clone for Frontier<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn clone(&self) -> Self {
+        Frontier {
+            height: self.height,
+            filled_subtrees: self.filled_subtrees,
+            next_index: self.next_index,
+            root: self.root,
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+}
+
+impl<H, C> From<&MerkleTree<H, C>> for Frontier<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn from(merkle_tree: &MerkleTree<H, C>) -> Self {
+        Frontier {
+            height: merkle_tree.height,
+            filled_subtrees: merkle_tree.filled_subtrees,
+            next_index: merkle_tree.next_index,
+            root: merkle_tree.last_root(),
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+}
+
+/// All slots of the root history of the created Merkle tree hold the root of
+/// the frontier, so no other root is known.
+impl<H, C> From<&Frontier<H, C>> for MerkleTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn from(frontier: &Frontier<H, C>) -> Self {
+        let mut merkle_tree = MerkleTree::new(frontier.height as usize);
+        merkle_tree.filled_subtrees = frontier.filled_subtrees;
+        merkle_tree.next_index = frontier.next_index;
+        merkle_tree.current_root_index =
+            (frontier.next_index / 2) % MERKLE_TREE_HISTORY_SIZE as u64;
+        merkle_tree.roots = [frontier.root; MERKLE_TREE_HISTORY_SIZE];
+        merkle_tree
+    }
+}
@@ -1,11 +1,13 @@
 use anchor_lang::solana_program::blake3::{hash, hashv};
 
-use crate::{Hash, Hasher};
+use crate::{Hash, HashFunction, Hasher};
 
 #[derive(Clone, Copy)] // To allow using with zero copy Solana accounts.
 pub struct Blake3;
 
 impl Hasher for Blake3 {
+    const HASH_FUNCTION: HashFunction = HashFunction::Blake3;
+
     fn hash(val: &[u8]) -> Hash {
         hash(val).to_bytes()
     }
@@ -1,11 +1,13 @@
 use anchor_lang::solana_program::keccak::{hash, hashv};
 
-use crate::{Hash, Hasher};
+use crate::{Hash, HashFunction, Hasher};
 
 #[derive(Clone, Copy)] // To allow using with zero copy Solana accounts.
 pub struct Keccak;
 
 impl Hasher for Keccak {
+    const HASH_FUNCTION: HashFunction = HashFunction::Keccak;
+
     fn hash(val: &[u8]) -> Hash {
         hash(val).to_bytes()
     }
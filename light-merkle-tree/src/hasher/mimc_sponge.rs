@@ -0,0 +1,82 @@
+use std::sync::OnceLock;
+
+use anchor_lang::solana_program::keccak;
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+use crate::{hasher::HASH_BYTES, Hash, HashFunction, Hasher};
+
+/// Number of rounds of the Feistel permutation.
+const ROUNDS: usize = 220;
+/// Seed of the round constants.
+const SEED: &[u8] = b"mimcsponge";
+
+/// MiMC sponge over the BN254 scalar field with circomlib parameters (220
+/// rounds, exponent 5), as used by Tornado Cash. Inputs are big-endian
+/// encoded field elements, absorbed one by one with a zero key, and the
+/// hash is the first element of the state.
+///
+/// Each input has to be smaller than the BN254 modulus, otherwise hashing
+/// panics. Leaves can be checked with
+/// [`is_valid_input`](Hasher::is_valid_input) before inserting them.
+#[derive(Clone, Copy)] // To allow using with zero copy Solana accounts.
+pub struct MimcSponge;
+
+/// Returns round constants, derived from the seed with a Keccak hash chain.
+/// The first and the last constant are zero.
+fn round_constants() -> &'static [Fr; ROUNDS] {
+    static ROUND_CONSTANTS: OnceLock<[Fr; ROUNDS]> = OnceLock::new();
+    ROUND_CONSTANTS.get_or_init(|| {
+        let mut constants = [Fr::zero(); ROUNDS];
+        let mut hash = keccak::hash(SEED).to_bytes();
+        for constant in constants[1..ROUNDS - 1].iter_mut() {
+            hash = keccak::hash(&hash).to_bytes();
+            *constant = Fr::from_be_bytes_mod_order(&hash);
+        }
+        constants
+    })
+}
+
+/// Applies the Feistel permutation to the state.
+fn permute(mut left: Fr, mut right: Fr) -> (Fr, Fr) {
+    let constants = round_constants();
+    for (i, constant) in constants.iter().enumerate() {
+        let t = left + constant;
+        let t2 = t * t;
+        let t5 = t2 * t2 * t;
+        if i < ROUNDS - 1 {
+            (left, right) = (right + t5, left);
+        } else {
+            right += t5;
+        }
+    }
+    (left, right)
+}
+
+fn to_field_element(val: &[u8]) -> Option<Fr> {
+    let mut bytes = [0; HASH_BYTES];
+    bytes[HASH_BYTES.checked_sub(val.len())?..].copy_from_slice(val);
+    let element = Fr::from_be_bytes_mod_order(&bytes);
+    (element.into_bigint().to_bytes_be() == bytes).then_some(element)
+}
+
+impl Hasher for MimcSponge {
+    const HASH_FUNCTION: HashFunction = HashFunction::MimcSponge;
+
+    fn hash(val: &[u8]) -> Hash {
+        Self::hashv(&[val])
+    }
+
+    fn hashv(vals: &[&[u8]]) -> Hash {
+        let (mut left, mut right) = (Fr::zero(), Fr::zero());
+        for val in vals {
+            left += to_field_element(val).expect("input is not a BN254 field element");
+            (left, right) = permute(left, right);
+        }
+        left.into_bigint().to_bytes_be().try_into().unwrap()
+    }
+
+    fn is_valid_input(val: &[u8]) -> bool {
+        to_field_element(val).is_some()
+    }
+}
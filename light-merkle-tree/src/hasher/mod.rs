@@ -1,9 +1,15 @@
+use crate::HashFunction;
+
 pub mod blake3;
 pub mod keccak;
+pub mod mimc_sponge;
+pub mod poseidon;
 pub mod sha256;
 
 pub use blake3::Blake3;
 pub use keccak::Keccak;
+pub use mimc_sponge::MimcSponge;
+pub use poseidon::Poseidon;
 pub use sha256::Sha256;
 
 pub const HASH_BYTES: usize = 32;
@@ -11,6 +17,23 @@ pub const HASH_BYTES: usize = 32;
 pub type Hash = [u8; HASH_BYTES];
 
 pub trait Hasher {
+    /// Identifier of the hash function, stored in Solana accounts.
+    const HASH_FUNCTION: HashFunction;
+    /// Maximum number of values hashed at once with
+    /// [`hashv`](Hasher::hashv).
+    const MAX_INPUTS: usize = usize::MAX;
+
+    /// Hashes the given value. Panics if it's not a valid input (see
+    /// [`is_valid_input`](Hasher::is_valid_input)).
     fn hash(val: &[u8]) -> Hash;
+    /// Hashes the concatenation of the given values. Panics if any of them is
+    /// not a valid input (see [`is_valid_input`](Hasher::is_valid_input)).
     fn hashv(vals: &[&[u8]]) -> Hash;
+
+    /// Checks whether the given value can be hashed. All values are valid
+    /// inputs unless the hash function restricts them, like
+    /// [`Poseidon`](Poseidon) does.
+    fn is_valid_input(_val: &[u8]) -> bool {
+        true
+    }
 }
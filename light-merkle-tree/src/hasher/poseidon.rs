@@ -0,0 +1,36 @@
+use ark_bn254::Fr;
+use light_poseidon::PoseidonBytesHasher;
+
+use crate::{Hash, HashFunction, Hasher};
+
+/// Maximum number of inputs hashed at once, limited by the width of circom
+/// parameters (13).
+pub const MAX_INPUTS: usize = 12;
+
+/// Poseidon hash over the BN254 scalar field, with circom-compatible
+/// parameters. Inputs are little-endian encoded field elements.
+///
+/// Each input has to be smaller than the BN254 modulus and at most
+/// [`MAX_INPUTS`](MAX_INPUTS) inputs can be hashed at once, otherwise
+/// hashing panics. Leaves can be checked with
+/// [`is_valid_input`](Hasher::is_valid_input) before inserting them.
+#[derive(Clone, Copy)] // To allow using with zero copy Solana accounts.
+pub struct Poseidon;
+
+impl Hasher for Poseidon {
+    const HASH_FUNCTION: HashFunction = HashFunction::Poseidon;
+    const MAX_INPUTS: usize = MAX_INPUTS;
+
+    fn hash(val: &[u8]) -> Hash {
+        Self::hashv(&[val])
+    }
+
+    fn hashv(vals: &[&[u8]]) -> Hash {
+        let mut hasher = light_poseidon::Poseidon::<Fr>::new_circom(vals.len()).unwrap();
+        hasher.hash_bytes_le(vals).unwrap()
+    }
+
+    fn is_valid_input(val: &[u8]) -> bool {
+        light_poseidon::bytes_to_prime_field_element_le::<Fr>(val).is_ok()
+    }
+}
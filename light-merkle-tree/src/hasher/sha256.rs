@@ -1,11 +1,13 @@
 use anchor_lang::solana_program::hash::{hash, hashv};
 
-use crate::{Hash, Hasher};
+use crate::{Hash, HashFunction, Hasher};
 
 #[derive(Clone, Copy)] // To allow using with zero copy Solana accounts.
 pub struct Sha256;
 
 impl Hasher for Sha256 {
+    const HASH_FUNCTION: HashFunction = HashFunction::Sha256;
+
     fn hash(val: &[u8]) -> Hash {
         hash(val).to_bytes()
     }
@@ -0,0 +1,149 @@
+use std::marker::PhantomData;
+
+use crate::{config::MerkleTreeConfig, constants::extended_zero_bytes, hasher::Hasher, HASH_LEN};
+
+/// Computes the root of a Merkle tree from a stream of leaves, keeping only
+/// one pending node per level.
+///
+/// The root is the same as [`MerkleTree::last_root`](crate::MerkleTree::last_root)
+/// after inserting the same leaves. If the number of leaves is odd, the last
+/// one is paired with the empty leaf (with
+/// [`MIX_IN_LENGTH`](MerkleTreeConfig::MIX_IN_LENGTH), the mixed-in number of
+/// leaves doesn't include it).
+///
+/// Unlike `MerkleTree`, the height isn't limited to
+/// [`MAX_HEIGHT`](crate::MAX_HEIGHT). Zero bytes of higher levels are
+/// derived from `C::ZERO_BYTES`.
+pub struct IncrementalRoot<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    height: usize,
+    /// Left nodes waiting for their right siblings, one per level. The last
+    /// one is set only when the tree is full.
+    frontier: Vec<Option<[u8; HASH_LEN]>>,
+    /// Number of consumed leaves.
+    len: u64,
+    /// Zero bytes up to the level of the root.
+    zero_bytes: Vec<[u8; HASH_LEN]>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> IncrementalRoot<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub fn new(height: usize) -> Self {
+        assert!(height > 0);
+        // The number of leaves has to fit in `u64`.
+        assert!(height < u64::BITS as usize);
+
+        IncrementalRoot {
+            height,
+            frontier: vec![None; height + 1],
+            len: 0,
+            zero_bytes: extended_zero_bytes::<H, C>(height),
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    /// Computes the root of a tree with the given height and leaves.
+    pub fn compute<I>(height: usize, leaves: I) -> [u8; HASH_LEN]
+    where
+        I: IntoIterator<Item = [u8; HASH_LEN]>,
+    {
+        let mut incremental_root = Self::new(height);
+        incremental_root.extend(leaves);
+        incremental_root.root()
+    }
+
+    /// Number of consumed leaves.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn push(&mut self, leaf: [u8; HASH_LEN]) {
+        // Check if the leaf doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.len, 1 << self.height);
+
+        let mut node = leaf;
+        let mut level = 0;
+        while let Some(left) = self.frontier[level].take() {
+            node = C::hash_pair::<H>(&left, &node);
+            level += 1;
+        }
+        self.frontier[level] = Some(node);
+        self.len += 1;
+    }
+
+    /// Returns the root of the tree with all consumed leaves, padded with
+    /// zero bytes.
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        if self.len == 0 {
+            let empty_root = self.zero_bytes[C::empty_root_level(self.height)];
+            return C::mix_root::<H>(&empty_root, 0);
+        }
+
+        C::mix_root::<H>(&self.root_node(), self.len)
+    }
+
+    /// Returns the root node of the tree with all consumed leaves, without
+    /// mixing in the number of leaves. Unlike [`root`](IncrementalRoot::root),
+    /// it's a plain node, which can be a sibling in a path.
+    pub(crate) fn root_node(&self) -> [u8; HASH_LEN] {
+        if self.len == 0 {
+            return self.zero_bytes[self.height];
+        }
+
+        let mut node: Option<[u8; HASH_LEN]> = None;
+        for level in 0..self.height {
+            node = match (self.frontier[level], node) {
+                (Some(left), Some(right)) => Some(C::hash_pair::<H>(&left, &right)),
+                (Some(left), None) => Some(C::hash_pair::<H>(&left, &self.zero_bytes[level])),
+                (None, Some(left)) => Some(C::hash_pair::<H>(&left, &self.zero_bytes[level])),
+                (None, None) => None,
+            };
+        }
+
+        // The only pending node of a full tree is the root.
+        self.frontier[self.height].or(node).unwrap()
+    }
+}
+
+impl<H, C> Extend<[u8; HASH_LEN]> for IncrementalRoot<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn extend<I: IntoIterator<Item = [u8; HASH_LEN]>>(&mut self, leaves: I) {
+        for leaf in leaves {
+            self.push(leaf);
+        }
+    }
+}
+
+impl<H, C> Clone for IncrementalRoot<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn clone(&self) -> Self {
+        IncrementalRoot {
+            height: self.height,
+            frontier: self.frontier.clone(),
+            len: self.len,
+            zero_bytes: self.zero_bytes.clone(),
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    config::MerkleTreeConfig, hasher::Hasher, incremental_root::IncrementalRoot,
+    mixed_root::compute_root, MerkleTree, HASH_LEN,
+};
+
+/// Authentication path of a single leaf, kept up to date as new leaves are
+/// appended to the tree, without storing the tree.
+///
+/// Siblings on the left of the path are known when the witness is created.
+/// Siblings on the right are roots of subtrees filled with leaves appended
+/// later, from the bottom up, so only the root of the one currently being
+/// filled has to be computed incrementally.
+pub struct IncrementalWitness<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    height: usize,
+    index: u64,
+    leaf: [u8; HASH_LEN],
+    /// Siblings of the path, starting from the bottom. Right siblings which
+    /// aren't filled yet are zero bytes.
+    path: Vec<[u8; HASH_LEN]>,
+    /// Level and partial root of the right sibling currently being filled.
+    cursor: Option<(usize, IncrementalRoot<H, C>)>,
+    /// Next index to insert a leaf.
+    next_index: u64,
+}
+
+impl<H, C> IncrementalWitness<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Creates a witness of one of the leaves of the last insert into the
+    /// given Merkle tree. `leaf1` and `leaf2` are the inserted leaves and
+    /// `index` is the index of the witnessed one, which is either
+    /// `next_index - 2` (`leaf1`) or `next_index - 1` (`leaf2`).
+    pub fn from_tree(
+        merkle_tree: &MerkleTree<H, C>,
+        leaf1: [u8; HASH_LEN],
+        leaf2: [u8; HASH_LEN],
+        index: u64,
+    ) -> Self {
+        let next_index = merkle_tree.next_index;
+        assert!(next_index >= 2);
+        assert!(index == next_index - 2 || index == next_index - 1);
+
+        let height = merkle_tree.height as usize;
+        let (leaf, sibling) = if index.is_multiple_of(2) {
+            (leaf1, leaf2)
+        } else {
+            (leaf2, leaf1)
+        };
+
+        let mut path = Vec::with_capacity(height);
+        path.push(sibling);
+        for level in 1..height {
+            if (index >> level).is_multiple_of(2) {
+                path.push(C::ZERO_BYTES[level]);
+            } else {
+                // The witnessed leaf is on the path of the last insert, so
+                // the left sibling is the last filled subtree.
+                path.push(merkle_tree.filled_subtrees[level]);
+            }
+        }
+
+        Self::from_path(height, index, leaf, path, next_index)
+    }
+
+    /// Creates a witness from its path. Right siblings which aren't filled
+    /// yet have to be zero bytes.
+    pub(crate) fn from_path(
+        height: usize,
+        index: u64,
+        leaf: [u8; HASH_LEN],
+        path: Vec<[u8; HASH_LEN]>,
+        next_index: u64,
+    ) -> Self {
+        IncrementalWitness {
+            height,
+            index,
+            leaf,
+            path,
+            cursor: None,
+            next_index,
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn leaf(&self) -> [u8; HASH_LEN] {
+        self.leaf
+    }
+
+    /// Updates the path with a leaf appended to the tree.
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) {
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.next_index, 2u64.pow(self.height as u32));
+
+        // The right sibling containing the next leaf is on the level where
+        // paths of both leaves split.
+        let level = (u64::BITS - 1 - (self.index ^ self.next_index).leading_zeros()) as usize;
+        self.next_index += 1;
+        if level == 0 {
+            self.path[0] = leaf;
+            return;
+        }
+
+        let (_, cursor) = self
+            .cursor
+            .get_or_insert_with(|| (level, IncrementalRoot::new(level)));
+        cursor.push(leaf);
+
+        if cursor.len() == 1 << level {
+            self.path[level] = cursor.root_node();
+            self.cursor = None;
+        }
+    }
+
+    /// Updates the path with a pair of leaves inserted with
+    /// [`MerkleTree::insert`](MerkleTree::insert).
+    pub fn insert(&mut self, leaf1: [u8; HASH_LEN], leaf2: [u8; HASH_LEN]) {
+        self.append(leaf1);
+        self.append(leaf2);
+    }
+
+    /// Returns siblings of the witnessed leaf, starting from the bottom.
+    pub fn path(&self) -> Vec<[u8; HASH_LEN]> {
+        let mut path = self.path.clone();
+        if let Some((level, cursor)) = &self.cursor {
+            path[*level] = cursor.root_node();
+        }
+        path
+    }
+
+    /// Returns the root of the tree with all appended leaves.
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        let root = compute_root::<H, C>(self.leaf, self.index, &self.path());
+        C::mix_root::<H>(&root, self.next_index)
+    }
+}
+
+impl<H, C> Clone for IncrementalWitness<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn clone(&self) -> Self {
+        IncrementalWitness {
+            height: self.height,
+            index: self.index,
+            leaf: self.leaf,
+            path: self.path.clone(),
+            cursor: self.cursor.clone(),
+            next_index: self.next_index,
+        }
+    }
+}
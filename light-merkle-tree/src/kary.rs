@@ -0,0 +1,292 @@
+use std::marker::PhantomData;
+
+use crate::{
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+    HASH_LEN,
+};
+
+/// Computes `depth` levels of zero bytes of a tree with the given arity,
+/// starting with the empty leaf. Every next level is a hash of `ARITY`
+/// copies of the previous one.
+///
+/// Panics if `H` can't hash `ARITY` values at once.
+pub fn zero_bytes<H: Hasher, const ARITY: usize>(
+    empty_leaf: [u8; HASH_LEN],
+    depth: usize,
+) -> Vec<Hash> {
+    check_arity::<H, ARITY>();
+
+    let mut zero_bytes = Vec::with_capacity(depth);
+    let mut prev_hash = empty_leaf;
+    for _ in 0..depth {
+        zero_bytes.push(prev_hash);
+        prev_hash = H::hashv(&[&prev_hash[..]; ARITY]);
+    }
+    zero_bytes
+}
+
+fn hash_children<H: Hasher>(children: &[Hash]) -> Hash {
+    let children: Vec<&[u8]> = children.iter().map(|child| child.as_slice()).collect();
+    H::hashv(&children)
+}
+
+/// Checks whether `H` can hash children of a node at once. Poseidon, with
+/// circom parameters, is limited to
+/// [`poseidon::MAX_INPUTS`](crate::hasher::poseidon::MAX_INPUTS) children.
+fn check_arity<H: Hasher, const ARITY: usize>() {
+    assert!(ARITY >= 2);
+    assert!(
+        ARITY <= H::MAX_INPUTS,
+        "arity {ARITY} exceeds the maximum number of inputs of the hash function ({})",
+        H::MAX_INPUTS
+    );
+}
+
+fn check_params<H: Hasher, C: MerkleTreeConfig, const ARITY: usize>(height: usize) {
+    check_arity::<H, ARITY>();
+    // Children are hashed in order and the root isn't mixed with the number
+    // of leaves, so configs relying on that aren't supported.
+    assert!(
+        !C::SORTED_PAIRS && !C::MIX_IN_LENGTH,
+        "k-ary trees support neither sorted pairs nor mixing in the length"
+    );
+    assert!(height > 0);
+    // Check if the capacity fits in `u64`.
+    assert!((ARITY as u64).checked_pow(height as u32).is_some());
+}
+
+/// Frontier of a k-ary Merkle tree, the equivalent of the `filled_subtrees`
+/// of [`MerkleTree`](crate::MerkleTree). Leaves are appended one by one and
+/// empty subtrees are filled with zero bytes derived from `C::EMPTY_LEAF`.
+pub struct KaryFrontier<H, C, const ARITY: usize>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    height: usize,
+    /// Children of the last node on each level, the ones after the path of
+    /// the last leaf are stale.
+    filled_subtrees: Vec<[Hash; ARITY]>,
+    zero_bytes: Vec<Hash>,
+    root: Hash,
+    /// Next index to insert a leaf.
+    next_index: u64,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C, const ARITY: usize> KaryFrontier<H, C, ARITY>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub fn new(height: usize) -> Self {
+        check_params::<H, C, ARITY>(height);
+
+        let zero_bytes = zero_bytes::<H, ARITY>(C::EMPTY_LEAF, height + 1);
+        let filled_subtrees = zero_bytes[..height]
+            .iter()
+            .map(|zero| [*zero; ARITY])
+            .collect();
+
+        KaryFrontier {
+            height,
+            filled_subtrees,
+            root: zero_bytes[height],
+            zero_bytes,
+            next_index: 0,
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn next_index(&self) -> u64 {
+        self.next_index
+    }
+
+    pub fn root(&self) -> Hash {
+        self.root
+    }
+
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) {
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.next_index, (ARITY as u64).pow(self.height as u32));
+
+        let mut current_index = self.next_index as usize;
+        let mut current_level_hash = leaf;
+        for level in 0..self.height {
+            let position = current_index % ARITY;
+            let children = &mut self.filled_subtrees[level];
+            children[position] = current_level_hash;
+            children[position + 1..].fill(self.zero_bytes[level]);
+
+            current_level_hash = hash_children::<H>(children);
+            current_index /= ARITY;
+        }
+
+        self.root = current_level_hash;
+        self.next_index += 1;
+    }
+}
+
+/// Merkle tree where each node has `ARITY` children, hashed together with
+/// a single [`hashv`](Hasher::hashv) call. Such trees are shallower than
+/// binary ones, which makes them cheaper with hashes of wide permutations,
+/// e.g. Poseidon with width 5 for arity 4.
+///
+/// The tree keeps all non-empty nodes, so it can produce proofs. Empty
+/// subtrees are filled with zero bytes derived from `C::EMPTY_LEAF`.
+/// Configs with `SORTED_PAIRS` or `MIX_IN_LENGTH` aren't supported.
+pub struct KaryMerkleTree<H, C, const ARITY: usize>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Nodes level by level, starting with leaves and ending with the root.
+    levels: Vec<Vec<Hash>>,
+    zero_bytes: Vec<Hash>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C, const ARITY: usize> KaryMerkleTree<H, C, ARITY>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub fn new(height: usize) -> Self {
+        check_params::<H, C, ARITY>(height);
+
+        KaryMerkleTree {
+            levels: vec![vec![]; height + 1],
+            zero_bytes: zero_bytes::<H, ARITY>(C::EMPTY_LEAF, height + 1),
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    pub fn from_leaves(height: usize, leaves: &[[u8; HASH_LEN]]) -> Self {
+        let mut tree = Self::new(height);
+        assert!(leaves.len() as u64 <= (ARITY as u64).pow(height as u32));
+
+        tree.levels[0] = leaves.to_vec();
+        for level in 0..height {
+            let nodes = tree.levels[level]
+                .chunks(ARITY)
+                .map(|chunk| {
+                    let mut children = [tree.zero_bytes[level]; ARITY];
+                    children[..chunk.len()].copy_from_slice(chunk);
+                    hash_children::<H>(&children)
+                })
+                .collect();
+            tree.levels[level + 1] = nodes;
+        }
+        tree
+    }
+
+    pub fn height(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    pub fn leaves(&self) -> &[[u8; HASH_LEN]] {
+        &self.levels[0]
+    }
+
+    fn node(&self, level: usize, index: usize) -> Hash {
+        self.levels[level]
+            .get(index)
+            .copied()
+            .unwrap_or(self.zero_bytes[level])
+    }
+
+    pub fn root(&self) -> Hash {
+        self.node(self.height(), 0)
+    }
+
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) {
+        let height = self.height();
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(
+            self.levels[0].len() as u64,
+            (ARITY as u64).pow(height as u32)
+        );
+
+        self.levels[0].push(leaf);
+        let mut index = self.levels[0].len() - 1;
+        for level in 0..height {
+            let first = index - index % ARITY;
+            let children: Vec<Hash> = (first..first + ARITY)
+                .map(|child| self.node(level, child))
+                .collect();
+
+            index /= ARITY;
+            let hash = hash_children::<H>(&children);
+            if index < self.levels[level + 1].len() {
+                self.levels[level + 1][index] = hash;
+            } else {
+                self.levels[level + 1].push(hash);
+            }
+        }
+    }
+
+    /// Returns siblings of the leaf under the given index on each level,
+    /// starting from the bottom. Each level has `ARITY - 1` siblings, in the
+    /// order of the children without the node on the path.
+    pub fn proof(&self, index: usize) -> Option<Vec<Vec<Hash>>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut index = index;
+        let proof = (0..self.height())
+            .map(|level| {
+                let first = index - index % ARITY;
+                let siblings = (first..first + ARITY)
+                    .filter(|child| *child != index)
+                    .map(|child| self.node(level, child))
+                    .collect();
+                index /= ARITY;
+                siblings
+            })
+            .collect();
+        Some(proof)
+    }
+}
+
+/// Verifies the proof returned by [`KaryMerkleTree::proof`](KaryMerkleTree::proof)
+/// against the root of a tree of the given height. The height binds the
+/// length of the proof, so inner nodes can't be proven as leaves.
+pub fn verify_proof<H: Hasher, const ARITY: usize>(
+    leaf: [u8; HASH_LEN],
+    index: u64,
+    height: usize,
+    proof: &[Vec<Hash>],
+    root: Hash,
+) -> bool {
+    if proof.len() != height {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut index = index;
+    for siblings in proof {
+        if siblings.len() != ARITY - 1 {
+            return false;
+        }
+
+        let position = (index % ARITY as u64) as usize;
+        let mut children = siblings.clone();
+        children.insert(position, node);
+        node = hash_children::<H>(&children);
+        index /= ARITY as u64;
+    }
+
+    index == 0 && node == root
+}
@@ -1,4 +1,4 @@
-use std::marker::PhantomData;
+use std::{convert::Infallible, marker::PhantomData, result::Result};
 
 #[cfg(feature = "solana")]
 use anchor_lang::prelude::*;
@@ -7,20 +7,103 @@ use bytemuck::{Pod, Zeroable};
 use config::MerkleTreeConfig;
 use hasher::{Hash, Hasher};
 
+pub mod bitcoin;
 pub mod config;
 pub mod constants;
+pub mod frontier;
 pub mod hasher;
+pub mod incremental_root;
+pub mod incremental_witness;
+pub mod kary;
+pub mod mixed_root;
+pub mod mmr;
+pub mod pruned;
+pub mod range;
+pub mod sum;
+pub mod transparency;
+pub mod unbalanced;
+pub mod zero_copy;
 
 pub const DATA_LEN: usize = 32;
 pub const HASH_LEN: usize = 32;
 pub const MAX_HEIGHT: usize = 18;
 pub const MERKLE_TREE_HISTORY_SIZE: usize = 20;
 
-#[cfg(feature = "solana")]
-#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "solana", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum HashFunction {
     Sha256,
     Poseidon,
+    Keccak,
+    Blake3,
+    MimcSponge,
+}
+
+/// Returns the root of an empty Merkle tree with the given height (see
+/// [`FULL_HEIGHT_EMPTY_ROOT`](MerkleTreeConfig::FULL_HEIGHT_EMPTY_ROOT)).
+pub(crate) fn initial_root<H, C>(height: usize) -> Hash
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    C::mix_root::<H>(&C::ZERO_BYTES[C::empty_root_level(height)], 0)
+}
+
+/// Hashes the node under `index` on `level` up to the root of a tree with
+/// the given height. `sibling` is called with the level, index and hash of
+/// each node on the way and returns its sibling. Returns the root node,
+/// without mixing in the number of leaves.
+///
+/// This is the walk shared by all stores appending to trees compatible with
+/// [`MerkleTree`](MerkleTree).
+pub fn try_hash_to_root<H, C, E>(
+    height: usize,
+    level: usize,
+    index: u64,
+    node: Hash,
+    mut sibling: impl FnMut(usize, u64, &Hash) -> Result<Hash, E>,
+) -> Result<Hash, E>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    let (mut index, mut node) = (index, node);
+    for level in level..height {
+        let sibling = sibling(level, index, &node)?;
+        node = if index.is_multiple_of(2) {
+            C::hash_pair::<H>(&node, &sibling)
+        } else {
+            C::hash_pair::<H>(&sibling, &node)
+        };
+        index /= 2;
+    }
+
+    Ok(node)
+}
+
+/// Appends the node under `index` on `level` to filled subtrees of a tree
+/// with `filled_subtrees.len()` levels, with empty subtrees on the right.
+/// Returns the root node, without mixing in the number of leaves.
+pub(crate) fn append_to_filled_subtrees<H, C>(
+    filled_subtrees: &mut [[u8; HASH_LEN]],
+    level: usize,
+    index: u64,
+    node: Hash,
+) -> Hash
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    let height = filled_subtrees.len();
+    try_hash_to_root::<H, C, Infallible>(height, level, index, node, |level, index, node| {
+        Ok(if index.is_multiple_of(2) {
+            filled_subtrees[level] = *node;
+            C::ZERO_BYTES[level]
+        } else {
+            filled_subtrees[level]
+        })
+    })
+    .unwrap_or_else(|e| match e {})
 }
 
 // TODO(vadorovsky): Teach Anchor to accept `usize`, constants and const
@@ -66,23 +149,20 @@ where
     fn new_filled_subtrees(height: usize) -> [[u8; HASH_LEN]; MAX_HEIGHT] {
         let mut filled_subtrees = [[0; HASH_LEN]; MAX_HEIGHT];
 
-        for i in 0..height {
-            filled_subtrees[i] = C::ZERO_BYTES[i];
-        }
+        filled_subtrees[..height].copy_from_slice(&C::ZERO_BYTES[..height]);
 
         filled_subtrees
     }
 
     fn new_roots(height: usize) -> [[u8; HASH_LEN]; MERKLE_TREE_HISTORY_SIZE] {
         let mut roots = [[0; HASH_LEN]; MERKLE_TREE_HISTORY_SIZE];
-        roots[0] = C::ZERO_BYTES[height - 1];
+        roots[0] = initial_root::<H, C>(height);
 
         roots
     }
 
     /// Create a new Merkle tree with the given height.
-    #[cfg(not(feature = "solana"))]
-    pub fn new(height: usize, #[cfg(feature = "solana")] hash_function: HashFunction) -> Self {
+    pub fn new(height: usize) -> Self {
         Self::check_height(height);
 
         let filled_subtrees = Self::new_filled_subtrees(height);
@@ -95,50 +175,147 @@ where
             next_index: 0,
             current_root_index: 0,
             #[cfg(feature = "solana")]
-            hash_function,
+            hash_function: H::HASH_FUNCTION,
             hasher: PhantomData,
             config: PhantomData,
         }
     }
 
     /// Initialize the Merkle tree with subtrees and roots based on the given
-    /// height.
-    #[cfg(feature = "solana")]
-    pub fn init(&mut self, height: usize, hash_function: HashFunction) {
+    /// height. Meant to be used on already allocated (e.g. zero-copy)
+    /// accounts.
+    pub fn init(&mut self, height: usize) {
         Self::check_height(height);
 
         self.height = height as u64;
         self.filled_subtrees = Self::new_filled_subtrees(height);
         self.roots = Self::new_roots(height);
-        self.hash_function = hash_function;
+        self.next_index = 0;
+        self.current_root_index = 0;
+        #[cfg(feature = "solana")]
+        {
+            self.hash_function = H::HASH_FUNCTION;
+        }
     }
 
-    pub fn hash(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) -> Hash {
-        H::hashv(&[&leaf1, &leaf2])
-    }
+    /// Hashes pairs of nodes of the given level, padding the last pair with
+    /// zero bytes. With the `rayon` feature, pairs are hashed in parallel.
+    fn hash_level(nodes: &[[u8; HASH_LEN]], level: usize) -> Vec<[u8; HASH_LEN]> {
+        let hash_pair = |pair: &[[u8; HASH_LEN]]| {
+            C::hash_pair::<H>(&pair[0], pair.get(1).unwrap_or(&C::ZERO_BYTES[level]))
+        };
 
-    pub fn insert(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) {
-        // Check if next index doesn't exceed the Merkle tree capacity.
-        assert_ne!(self.next_index, 2u64.pow(self.height as u32));
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            nodes.par_chunks(2).map(hash_pair).collect()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            nodes.chunks(2).map(hash_pair).collect()
+        }
+    }
 
-        let mut current_index = self.next_index / 2;
-        let mut current_level_hash = self.hash(leaf1, leaf2);
+    /// Computes the root of the tree containing only the first `len` leaves,
+    /// using nodes of the full tree which don't depend on further leaves.
+    fn prefix_root(levels: &[Vec<[u8; HASH_LEN]>], len: usize) -> [u8; HASH_LEN] {
+        let mut index = len - 1;
+        let mut node = levels[0][index];
 
-        for i in 1..self.height as usize {
-            let (left, right) = if current_index % 2 == 0 {
-                self.filled_subtrees[i] = current_level_hash;
-                (current_level_hash, C::ZERO_BYTES[i])
+        for (level, nodes) in levels[..levels.len() - 1].iter().enumerate() {
+            node = if index.is_multiple_of(2) {
+                C::hash_pair::<H>(&node, &C::ZERO_BYTES[level])
             } else {
-                (self.filled_subtrees[i], current_level_hash)
+                C::hash_pair::<H>(&nodes[index - 1], &node)
             };
+            index /= 2;
+        }
 
-            current_index /= 2;
-            current_level_hash = self.hash(left, right);
+        node
+    }
+
+    /// Creates a Merkle tree with the given leaves, in the same state as
+    /// after inserting them pair by pair with
+    /// [`insert`](MerkleTree::insert).
+    ///
+    /// Returns also all non-empty nodes of the tree, level by level, starting
+    /// with leaves and ending with the root. Nodes of each level are hashed
+    /// in parallel when the `rayon` feature is enabled.
+    pub fn from_leaves(
+        height: usize,
+        leaves: &[[u8; DATA_LEN]],
+    ) -> (Vec<Vec<[u8; HASH_LEN]>>, Self) {
+        Self::check_height(height);
+        // Leaves are inserted in pairs.
+        assert!(leaves.len().is_multiple_of(2));
+        assert!(leaves.len() <= 1 << height);
+
+        let mut levels = Vec::with_capacity(height + 1);
+        levels.push(leaves.to_vec());
+        for level in 0..height {
+            let nodes = Self::hash_level(&levels[level], level);
+            levels.push(nodes);
         }
 
+        let mut merkle_tree = Self::new(height);
+        if leaves.is_empty() {
+            return (levels, merkle_tree);
+        }
+
+        let last_index = leaves.len() - 1;
+        for level in 1..height {
+            // The last left node on the path of the last leaf.
+            merkle_tree.filled_subtrees[level] = levels[level][(last_index >> level) & !1];
+        }
+
+        // Roots of the last inserts, which are still kept in the history.
+        let inserts = leaves.len() / 2;
+        let first = inserts.saturating_sub(MERKLE_TREE_HISTORY_SIZE - 1).max(1);
+        for insert in first..=inserts {
+            let root = Self::prefix_root(&levels, insert * 2);
+            merkle_tree.roots[insert % MERKLE_TREE_HISTORY_SIZE] =
+                C::mix_root::<H>(&root, insert as u64 * 2);
+        }
+        merkle_tree.current_root_index = (inserts % MERKLE_TREE_HISTORY_SIZE) as u64;
+        merkle_tree.next_index = leaves.len() as u64;
+
+        (levels, merkle_tree)
+    }
+
+    pub fn hash(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) -> Hash {
+        C::hash_pair::<H>(&leaf1, &leaf2)
+    }
+
+    pub fn insert(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) {
+        let hash = self.hash(leaf1, leaf2);
+        self.append_subtree(hash, 1);
+    }
+
+    /// Appends the root of a full subtree with `2^k` leaves, computed
+    /// off-chain, in one step instead of `2^(k-1)`
+    /// [`insert`](MerkleTree::insert) calls. The next index has to be a
+    /// multiple of `2^k`.
+    ///
+    /// Only one root is added to the root history, so roots of trees with
+    /// parts of the subtree are never known.
+    pub fn append_subtree(&mut self, subtree_root: [u8; HASH_LEN], k: usize) {
+        // Leaves are inserted in pairs.
+        assert!(k > 0);
+        assert!(k <= self.height as usize);
+        // Check if the subtree is aligned and it fits in the Merkle tree.
+        assert!(self.next_index.is_multiple_of(1 << k));
+        assert!(self.next_index + (1 << k) <= 2u64.pow(self.height as u32));
+
+        let root = append_to_filled_subtrees::<H, C>(
+            &mut self.filled_subtrees[..self.height as usize],
+            k,
+            self.next_index >> k,
+            subtree_root,
+        );
+
         self.current_root_index = (self.current_root_index + 1) % MERKLE_TREE_HISTORY_SIZE as u64;
-        self.roots[self.current_root_index as usize] = current_level_hash;
-        self.next_index += 2;
+        self.next_index += 1 << k;
+        self.roots[self.current_root_index as usize] = C::mix_root::<H>(&root, self.next_index);
     }
 
     pub fn is_known_root(&self, root: [u8; HASH_LEN]) -> bool {
@@ -147,7 +324,7 @@ where
                 return true;
             }
         }
-        return false;
+        false
     }
 
     pub fn last_root(&self) -> [u8; HASH_LEN] {
@@ -0,0 +1,61 @@
+use crate::{
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+    HASH_LEN,
+};
+
+/// Mixes the number of leaves into the root, the same way as the Ethereum
+/// deposit contract: `hash(root || le64(count) || [0; 24])`. Roots of trees
+/// with different numbers of leaves are then distinct, even if the missing
+/// leaves are empty.
+pub fn mix_in_length<H: Hasher>(root: &[u8; HASH_LEN], count: u64) -> Hash {
+    let mut length = [0u8; HASH_LEN];
+    length[..8].copy_from_slice(&count.to_le_bytes());
+    H::hashv(&[root, &length])
+}
+
+/// Computes the root node from a leaf and its proof (siblings starting from
+/// the bottom), without mixing in the number of leaves.
+pub fn compute_root<H, C>(leaf: [u8; HASH_LEN], index: u64, proof: &[[u8; HASH_LEN]]) -> Hash
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    proof
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (level, sibling)| {
+            if (index >> level).is_multiple_of(2) {
+                C::hash_pair::<H>(&node, sibling)
+            } else {
+                C::hash_pair::<H>(sibling, &node)
+            }
+        })
+}
+
+/// Verifies the proof of the leaf under the given index against a root
+/// recorded for a tree with the given height and `count` leaves. With
+/// [`MIX_IN_LENGTH`](MerkleTreeConfig::MIX_IN_LENGTH), the proof is valid
+/// only for the number of leaves the root commits to.
+///
+/// The proof has to have a sibling for each level, otherwise an inner node
+/// with a shorter proof could pass as a leaf.
+pub fn verify_proof<H, C>(
+    height: usize,
+    leaf: [u8; HASH_LEN],
+    index: u64,
+    proof: &[[u8; HASH_LEN]],
+    count: u64,
+    root: [u8; HASH_LEN],
+) -> bool
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    proof.len() == height
+        && 1u64
+            .checked_shl(height as u32)
+            .is_some_and(|capacity| index < capacity)
+        && index < count
+        && C::mix_root::<H>(&compute_root::<H, C>(leaf, index, proof), count) == root
+}
@@ -0,0 +1,240 @@
+use std::marker::PhantomData;
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    hasher::{Hash, Hasher},
+    HASH_LEN,
+};
+
+/// Maximum number of peaks, one for each bit of the number of leaves.
+pub const MAX_PEAKS: usize = 64;
+
+/// Bags peaks (ordered from the highest one) into a single root, from the
+/// right: `H(p0, H(p1, ... H(pn-1, pn)))`. Returns `None` if there are no
+/// peaks.
+pub fn bag_peaks<H: Hasher>(peaks: &[Hash]) -> Option<Hash> {
+    let (last, rest) = peaks.split_last()?;
+    Some(
+        rest.iter()
+            .rev()
+            .fold(*last, |acc, peak| H::hashv(&[peak, &acc])),
+    )
+}
+
+/// Computes the root of a range with the given number of leaves and peaks:
+/// `H(le64(leaf_count) || bag_peaks(peaks))`. The number of leaves
+/// determines heights of the peaks, so committing to it prevents proving an
+/// inner node as a leaf of a smaller range. Returns `None` if there are no
+/// peaks.
+pub fn root<H: Hasher>(leaf_count: u64, peaks: &[Hash]) -> Option<Hash> {
+    let bagged = bag_peaks::<H>(peaks)?;
+    Some(H::hashv(&[&leaf_count.to_le_bytes(), &bagged]))
+}
+
+/// Compact state of a Merkle Mountain Range, which keeps only its peaks. It
+/// can be stored in a zero-copy account and it's enough to append leaves
+/// and compute the root.
+///
+/// A range with `n` leaves has a peak for each set bit of `n`, which is the
+/// root of a complete subtree with `2^bit` leaves. Peaks are ordered from the
+/// highest one.
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MmrState {
+    /// Number of appended leaves.
+    pub leaf_count: u64,
+    /// Peaks, only the first `leaf_count.count_ones()` are set.
+    pub peaks: [[u8; HASH_LEN]; MAX_PEAKS],
+}
+
+impl MmrState {
+    pub fn new() -> Self {
+        Self::zeroed()
+    }
+
+    pub fn peaks(&self) -> &[Hash] {
+        &self.peaks[..self.leaf_count.count_ones() as usize]
+    }
+
+    pub fn append<H: Hasher>(&mut self, leaf: [u8; HASH_LEN]) {
+        // Check if the number of leaves doesn't overflow.
+        assert_ne!(self.leaf_count, u64::MAX);
+
+        // Merge peaks of the same height, like carrying bits when
+        // incrementing the number of leaves.
+        let mut peaks_len = self.peaks().len();
+        let mut node = leaf;
+        let mut height = 0;
+        while self.leaf_count >> height & 1 == 1 {
+            peaks_len -= 1;
+            node = H::hashv(&[&self.peaks[peaks_len], &node]);
+            height += 1;
+        }
+        self.peaks[peaks_len] = node;
+        self.peaks[peaks_len + 1..].fill([0; HASH_LEN]);
+        self.leaf_count += 1;
+    }
+
+    /// Returns the root committing to the number of leaves and bagged peaks,
+    /// or `None` if there are no leaves.
+    pub fn root<H: Hasher>(&self) -> Option<Hash> {
+        root::<H>(self.leaf_count, self.peaks())
+    }
+
+    /// Verifies the proof of a leaf against the peaks of this state.
+    pub fn verify_proof<H: Hasher>(&self, leaf: [u8; HASH_LEN], proof: &MmrProof) -> bool {
+        proof.leaf_count == self.leaf_count
+            && proof.peaks == self.peaks()
+            && self
+                .root::<H>()
+                .is_some_and(|root| verify_proof::<H>(leaf, proof, root))
+    }
+}
+
+impl Default for MmrState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Proof of inclusion of a leaf in a Merkle Mountain Range.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MmrProof {
+    pub leaf_index: u64,
+    /// Number of leaves in the range the proof was made for.
+    pub leaf_count: u64,
+    /// Siblings of the leaf up to its peak, starting from the bottom.
+    pub path: Vec<Hash>,
+    /// All peaks of the range, ordered from the highest one.
+    pub peaks: Vec<Hash>,
+}
+
+/// Returns the position of the peak containing the leaf under the given
+/// index and the height of that peak.
+fn peak_of(leaf_index: u64, leaf_count: u64) -> Option<(usize, usize)> {
+    if leaf_index >= leaf_count {
+        return None;
+    }
+
+    let mut start = 0;
+    let mut position = 0;
+    for height in (0..u64::BITS as usize).rev() {
+        if leaf_count >> height & 1 == 1 {
+            start += 1 << height;
+            if leaf_index < start {
+                return Some((position, height));
+            }
+            position += 1;
+        }
+    }
+    None
+}
+
+/// Verifies the proof of a leaf against the [root](root) of the range. The
+/// number of leaves and peaks of the proof are checked against the root, so
+/// they don't have to be trusted.
+pub fn verify_proof<H: Hasher>(leaf: [u8; HASH_LEN], proof: &MmrProof, root: Hash) -> bool {
+    let (position, height) = match peak_of(proof.leaf_index, proof.leaf_count) {
+        Some(peak) => peak,
+        None => return false,
+    };
+    if proof.path.len() != height || proof.peaks.len() != proof.leaf_count.count_ones() as usize {
+        return false;
+    }
+
+    let peak = proof
+        .path
+        .iter()
+        .enumerate()
+        .fold(leaf, |node, (level, sibling)| {
+            if (proof.leaf_index >> level).is_multiple_of(2) {
+                H::hashv(&[&node, sibling])
+            } else {
+                H::hashv(&[sibling, &node])
+            }
+        });
+
+    peak == proof.peaks[position] && self::root::<H>(proof.leaf_count, &proof.peaks) == Some(root)
+}
+
+/// Merkle Mountain Range which keeps all nodes, so it can produce proofs.
+/// Unlike [`MerkleTree`](crate::MerkleTree), its height isn't chosen up
+/// front, it grows with appended leaves.
+pub struct Mmr<H>
+where
+    H: Hasher,
+{
+    /// Roots of complete subtrees level by level, starting with leaves.
+    levels: Vec<Vec<Hash>>,
+    state: MmrState,
+
+    hasher: PhantomData<H>,
+}
+
+impl<H> Mmr<H>
+where
+    H: Hasher,
+{
+    pub fn new() -> Self {
+        Mmr {
+            levels: vec![vec![]],
+            state: MmrState::new(),
+            hasher: PhantomData,
+        }
+    }
+
+    pub fn leaf_count(&self) -> u64 {
+        self.state.leaf_count
+    }
+
+    /// Compact state with peaks of the range.
+    pub fn state(&self) -> &MmrState {
+        &self.state
+    }
+
+    pub fn root(&self) -> Option<Hash> {
+        self.state.root::<H>()
+    }
+
+    /// Appends a leaf. Returns its index.
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) -> u64 {
+        self.levels[0].push(leaf);
+        let mut level = 0;
+        while self.levels[level].len().is_multiple_of(2) {
+            let nodes = &self.levels[level];
+            let node = H::hashv(&[&nodes[nodes.len() - 2], &nodes[nodes.len() - 1]]);
+            if self.levels.len() == level + 1 {
+                self.levels.push(vec![]);
+            }
+            self.levels[level + 1].push(node);
+            level += 1;
+        }
+
+        self.state.append::<H>(leaf);
+        self.state.leaf_count - 1
+    }
+
+    pub fn proof(&self, leaf_index: u64) -> Option<MmrProof> {
+        let (_, height) = peak_of(leaf_index, self.state.leaf_count)?;
+
+        let path = (0..height)
+            .map(|level| self.levels[level][((leaf_index >> level) ^ 1) as usize])
+            .collect();
+        Some(MmrProof {
+            leaf_index,
+            leaf_count: self.state.leaf_count,
+            path,
+            peaks: self.state.peaks().to_vec(),
+        })
+    }
+}
+
+impl<H> Default for Mmr<H>
+where
+    H: Hasher,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::{
+    config::MerkleTreeConfig, hasher::Hasher, incremental_witness::IncrementalWitness,
+    initial_root, HASH_LEN, MAX_HEIGHT,
+};
+
+/// State of a [`PrunedMerkleTree`](PrunedMerkleTree) saved by
+/// [`checkpoint`](PrunedMerkleTree::checkpoint).
+struct Checkpoint<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    next_index: u64,
+    leaf: [u8; HASH_LEN],
+    ommers: [[u8; HASH_LEN]; MAX_HEIGHT],
+    marked: BTreeMap<u64, IncrementalWitness<H, C>>,
+}
+
+/// Merkle tree which keeps only its frontier and paths of marked leaves,
+/// similar to `bridgetree` used by Zcash.
+///
+/// Leaves are appended one by one and the root is the same as
+/// [`MerkleTree::last_root`](crate::MerkleTree::last_root) after inserting
+/// the same leaves. If the number of leaves is odd, the last one is paired
+/// with the empty leaf (with
+/// [`MIX_IN_LENGTH`](MerkleTreeConfig::MIX_IN_LENGTH), the mixed-in number of
+/// leaves doesn't include it).
+pub struct PrunedMerkleTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    height: usize,
+    /// Next index to append a leaf.
+    next_index: u64,
+    /// The last appended leaf.
+    leaf: [u8; HASH_LEN],
+    /// Left siblings of the path of the last leaf. Only levels where the
+    /// path goes through a right child are set.
+    ommers: [[u8; HASH_LEN]; MAX_HEIGHT],
+    /// Witnesses of marked leaves, by their indices.
+    marked: BTreeMap<u64, IncrementalWitness<H, C>>,
+    checkpoints: VecDeque<Checkpoint<H, C>>,
+    max_checkpoints: usize,
+}
+
+impl<H, C> PrunedMerkleTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Creates an empty tree with the given height, keeping at most
+    /// `max_checkpoints` checkpoints.
+    pub fn new(height: usize, max_checkpoints: usize) -> Self {
+        assert!(height > 0);
+        assert!(height <= MAX_HEIGHT);
+
+        PrunedMerkleTree {
+            height,
+            next_index: 0,
+            leaf: [0; HASH_LEN],
+            ommers: [[0; HASH_LEN]; MAX_HEIGHT],
+            marked: BTreeMap::new(),
+            checkpoints: VecDeque::new(),
+            max_checkpoints,
+        }
+    }
+
+    /// Number of appended leaves.
+    pub fn next_index(&self) -> u64 {
+        self.next_index
+    }
+
+    pub fn append(&mut self, leaf: [u8; HASH_LEN]) {
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.next_index, 2u64.pow(self.height as u32));
+
+        if self.next_index > 0 {
+            // Combine the previous leaf with its left siblings, up to the
+            // first level where the path of the new leaf goes through
+            // a right child.
+            let mut node = self.leaf;
+            let mut level = 0;
+            while (self.next_index >> level).is_multiple_of(2) {
+                node = C::hash_pair::<H>(&self.ommers[level], &node);
+                level += 1;
+            }
+            self.ommers[level] = node;
+        }
+
+        for witness in self.marked.values_mut() {
+            witness.append(leaf);
+        }
+
+        self.leaf = leaf;
+        self.next_index += 1;
+    }
+
+    /// Appends a pair of leaves, the same way as
+    /// [`MerkleTree::insert`](crate::MerkleTree::insert).
+    pub fn insert(&mut self, leaf1: [u8; HASH_LEN], leaf2: [u8; HASH_LEN]) {
+        self.append(leaf1);
+        self.append(leaf2);
+    }
+
+    pub fn root(&self) -> [u8; HASH_LEN] {
+        // Same as the initial root of `MerkleTree`.
+        if self.next_index == 0 {
+            return initial_root::<H, C>(self.height);
+        }
+
+        let index = self.next_index - 1;
+        let root = (0..self.height).fold(self.leaf, |node, level| {
+            if (index >> level).is_multiple_of(2) {
+                C::hash_pair::<H>(&node, &C::ZERO_BYTES[level])
+            } else {
+                C::hash_pair::<H>(&self.ommers[level], &node)
+            }
+        });
+        C::mix_root::<H>(&root, self.next_index)
+    }
+
+    /// Marks the last appended leaf, so its proof is kept up to date.
+    /// Returns its index, or `None` if the tree is empty.
+    pub fn mark(&mut self) -> Option<u64> {
+        let index = self.next_index.checked_sub(1)?;
+
+        if !self.marked.contains_key(&index) {
+            let path = (0..self.height)
+                .map(|level| {
+                    if (index >> level).is_multiple_of(2) {
+                        C::ZERO_BYTES[level]
+                    } else {
+                        self.ommers[level]
+                    }
+                })
+                .collect();
+            self.marked.insert(
+                index,
+                IncrementalWitness::from_path(self.height, index, self.leaf, path, self.next_index),
+            );
+        }
+
+        Some(index)
+    }
+
+    /// Stops keeping the proof of the leaf under the given index. Returns
+    /// whether the leaf was marked.
+    pub fn unmark(&mut self, index: u64) -> bool {
+        self.marked.remove(&index).is_some()
+    }
+
+    /// Returns indices of marked leaves.
+    pub fn marked(&self) -> impl Iterator<Item = u64> + '_ {
+        self.marked.keys().copied()
+    }
+
+    /// Returns the witness of the marked leaf under the given index.
+    pub fn witness(&self, index: u64) -> Option<&IncrementalWitness<H, C>> {
+        self.marked.get(&index)
+    }
+
+    /// Returns siblings of the marked leaf under the given index, starting
+    /// from the bottom.
+    pub fn proof(&self, index: u64) -> Option<Vec<[u8; HASH_LEN]>> {
+        self.witness(index).map(|witness| witness.path())
+    }
+
+    /// Saves the current state, which can be restored with
+    /// [`rewind`](PrunedMerkleTree::rewind). The oldest checkpoint is
+    /// dropped when there are more than `max_checkpoints` of them.
+    pub fn checkpoint(&mut self) {
+        self.checkpoints.push_back(Checkpoint {
+            next_index: self.next_index,
+            leaf: self.leaf,
+            ommers: self.ommers,
+            marked: self.marked.clone(),
+        });
+        if self.checkpoints.len() > self.max_checkpoints {
+            self.checkpoints.pop_front();
+        }
+    }
+
+    /// Restores the state saved by the last checkpoint and removes it.
+    /// Leaves appended, marked and unmarked since then are reverted. Returns
+    /// `false` if there are no checkpoints.
+    pub fn rewind(&mut self) -> bool {
+        match self.checkpoints.pop_back() {
+            Some(checkpoint) => {
+                self.next_index = checkpoint.next_index;
+                self.leaf = checkpoint.leaf;
+                self.ommers = checkpoint.ommers;
+                self.marked = checkpoint.marked;
+                true
+            }
+            None => false,
+        }
+    }
+}
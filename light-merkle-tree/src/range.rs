@@ -0,0 +1,127 @@
+use crate::{
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+    HASH_LEN,
+};
+
+/// Proof that a contiguous range of leaves is in a Merkle tree. Instead of
+/// a proof per leaf, it contains only siblings on the boundaries of the
+/// range, which can't be computed from the leaves themselves.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct RangeProof {
+    /// Left siblings of the first node of the range, starting from the
+    /// bottom. Only levels on which the first node is a right child have
+    /// one.
+    pub left: Vec<Hash>,
+    /// Right siblings of the last node of the range, starting from the
+    /// bottom. Only levels on which the last node is a left child have one.
+    pub right: Vec<Hash>,
+}
+
+/// Creates a proof of leaves `[start, end)`, from non-empty nodes of a tree
+/// padded with zero bytes, level by level, as returned by
+/// [`MerkleTree::from_leaves`](crate::MerkleTree::from_leaves).
+///
+/// Returns `None` if the range is empty or outside of the leaves.
+pub fn range_proof<C>(
+    levels: &[Vec<[u8; HASH_LEN]>],
+    start: usize,
+    end: usize,
+) -> Option<RangeProof>
+where
+    C: MerkleTreeConfig,
+{
+    if start >= end || end > levels.first()?.len() {
+        return None;
+    }
+
+    let mut proof = RangeProof {
+        left: vec![],
+        right: vec![],
+    };
+    let (mut first, mut last) = (start, end - 1);
+    for (level, nodes) in levels[..levels.len() - 1].iter().enumerate() {
+        if !first.is_multiple_of(2) {
+            proof.left.push(nodes[first - 1]);
+        }
+        if last.is_multiple_of(2) {
+            proof
+                .right
+                .push(*nodes.get(last + 1).unwrap_or(&C::ZERO_BYTES[level]));
+        }
+        first /= 2;
+        last /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Computes the root node of a tree with the given height from leaves of
+/// the range starting at `start` and its proof, without mixing in the
+/// number of leaves.
+///
+/// Returns `None` if the range is empty or doesn't fit in the tree, or if
+/// the proof doesn't have the expected number of siblings.
+pub fn compute_root<H, C>(
+    height: usize,
+    start: u64,
+    leaves: &[[u8; HASH_LEN]],
+    proof: &RangeProof,
+) -> Option<Hash>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    let end = start.checked_add(leaves.len() as u64)?;
+    if leaves.is_empty() || end > 1u64.checked_shl(height as u32)? {
+        return None;
+    }
+
+    let mut left = proof.left.iter();
+    let mut right = proof.right.iter();
+    let mut nodes = leaves.to_vec();
+    let (mut first, mut last) = (start, end - 1);
+    for _ in 0..height {
+        if !first.is_multiple_of(2) {
+            nodes.insert(0, *left.next()?);
+        }
+        if last.is_multiple_of(2) {
+            nodes.push(*right.next()?);
+        }
+        nodes = nodes
+            .chunks(2)
+            .map(|pair| C::hash_pair::<H>(&pair[0], &pair[1]))
+            .collect();
+        first /= 2;
+        last /= 2;
+    }
+
+    // All siblings have to be used.
+    if left.next().is_some() || right.next().is_some() {
+        return None;
+    }
+
+    Some(nodes[0])
+}
+
+/// Verifies the proof of leaves of the range starting at `start` against a
+/// root recorded for a tree with `count` leaves. The range has to be within
+/// the inserted leaves.
+pub fn verify_proof<H, C>(
+    height: usize,
+    start: u64,
+    leaves: &[[u8; HASH_LEN]],
+    proof: &RangeProof,
+    count: u64,
+    root: [u8; HASH_LEN],
+) -> bool
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    start
+        .checked_add(leaves.len() as u64)
+        .is_some_and(|end| end <= count)
+        && compute_root::<H, C>(height, start, leaves, proof)
+            .is_some_and(|node| C::mix_root::<H>(&node, count) == root)
+}
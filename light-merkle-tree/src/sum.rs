@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use crate::{
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+};
+
+/// Node of a [`MerkleSumTree`](MerkleSumTree), committing to the sum of
+/// amounts of all leaves below.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SumNode {
+    pub hash: Hash,
+    pub sum: u64,
+}
+
+impl SumNode {
+    pub fn new(hash: Hash, sum: u64) -> Self {
+        SumNode { hash, sum }
+    }
+
+    /// Computes the parent of the given nodes, hashing
+    /// `left_hash || le64(left_sum) || right_hash || le64(right_sum)`.
+    /// Returns `None` if the sum overflows.
+    pub fn parent<H: Hasher>(left: &SumNode, right: &SumNode) -> Option<SumNode> {
+        let sum = left.sum.checked_add(right.sum)?;
+        let hash = H::hashv(&[
+            &left.hash,
+            &left.sum.to_le_bytes(),
+            &right.hash,
+            &right.sum.to_le_bytes(),
+        ]);
+        Some(SumNode { hash, sum })
+    }
+}
+
+/// Merkle sum tree, where each node commits to the sum of its children, as
+/// used by proofs of reserves. Leaves are hashes with amounts and empty
+/// leaves are `C::EMPTY_LEAF` with a zero amount.
+pub struct MerkleSumTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Nodes level by level, starting with leaves and ending with the root.
+    levels: Vec<Vec<SumNode>>,
+    /// Empty nodes on each level.
+    zero_nodes: Vec<SumNode>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> MerkleSumTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Builds a tree with the given height and leaves. Returns `None` if the
+    /// sum of amounts overflows.
+    pub fn from_leaves(height: usize, leaves: &[SumNode]) -> Option<Self> {
+        assert!(height > 0);
+        assert!(leaves.len() as u64 <= 1 << height);
+
+        let mut zero_nodes = vec![SumNode::new(C::EMPTY_LEAF, 0)];
+        for level in 0..height {
+            let zero = zero_nodes[level];
+            zero_nodes.push(SumNode::parent::<H>(&zero, &zero).unwrap());
+        }
+
+        let mut levels = vec![leaves.to_vec()];
+        for level in 0..height {
+            let nodes = levels[level]
+                .chunks(2)
+                .map(|pair| {
+                    SumNode::parent::<H>(&pair[0], pair.get(1).unwrap_or(&zero_nodes[level]))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            levels.push(nodes);
+        }
+
+        Some(MerkleSumTree {
+            levels,
+            zero_nodes,
+            hasher: PhantomData,
+            config: PhantomData,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    pub fn leaves(&self) -> &[SumNode] {
+        &self.levels[0]
+    }
+
+    fn node(&self, level: usize, index: usize) -> SumNode {
+        self.levels[level]
+            .get(index)
+            .copied()
+            .unwrap_or(self.zero_nodes[level])
+    }
+
+    /// Returns the root, whose sum is the total of all amounts.
+    pub fn root(&self) -> SumNode {
+        self.node(self.height(), 0)
+    }
+
+    /// Returns siblings of the leaf under the given index, with their sums,
+    /// starting from the bottom.
+    pub fn proof(&self, index: usize) -> Option<Vec<SumNode>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        Some(
+            (0..self.height())
+                .map(|level| self.node(level, (index >> level) ^ 1))
+                .collect(),
+        )
+    }
+}
+
+/// Verifies the proof of the leaf under the given index against the root of
+/// a tree of the given height. The height binds the length of the proof, so
+/// inner nodes can't be proven as leaves. Fails if any partial sum
+/// overflows.
+pub fn verify_proof<H: Hasher>(
+    leaf: SumNode,
+    index: u64,
+    height: usize,
+    proof: &[SumNode],
+    root: SumNode,
+) -> bool {
+    // Each level takes a bit of the index.
+    if proof.len() != height || height > u64::BITS as usize {
+        return false;
+    }
+
+    let mut node = leaf;
+    for (level, sibling) in proof.iter().enumerate() {
+        let parent = if (index >> level).is_multiple_of(2) {
+            SumNode::parent::<H>(&node, sibling)
+        } else {
+            SumNode::parent::<H>(sibling, &node)
+        };
+        node = match parent {
+            Some(parent) => parent,
+            None => return false,
+        };
+    }
+
+    index.checked_shr(proof.len() as u32).unwrap_or(0) == 0 && node == root
+}
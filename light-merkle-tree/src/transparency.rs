@@ -0,0 +1,267 @@
+use std::marker::PhantomData;
+
+use crate::hasher::{Hash, Hasher};
+
+/// Append-only log tree of RFC 9162 (Certificate Transparency version 2.0).
+///
+/// Unlike [`MerkleTree`](crate::MerkleTree), the tree isn't padded with
+/// zero bytes. A tree with `n` leaves is split into a left subtree with the
+/// largest power of two smaller than `n` leaves and a right subtree with the
+/// rest. Leaves and nodes are hashed with `0x00` and `0x01` prefixes,
+/// respectively.
+pub struct TransparencyLog<H>
+where
+    H: Hasher,
+{
+    /// Roots of complete subtrees, level by level. `nodes[level][i]` is the
+    /// root of the subtree with leaves `i * 2^level..(i + 1) * 2^level`, so
+    /// `nodes[0]` holds hashes of leaves. Every subtree of the recursive
+    /// split is either complete or the rightmost one, so roots of the log
+    /// take `O(log n)` hashes.
+    nodes: Vec<Vec<Hash>>,
+
+    hasher: PhantomData<H>,
+}
+
+impl<H> TransparencyLog<H>
+where
+    H: Hasher,
+{
+    pub fn new() -> Self {
+        TransparencyLog {
+            nodes: vec![Vec::new()],
+            hasher: PhantomData,
+        }
+    }
+
+    /// Hash of a leaf with the given data, `HASH(0x00 || data)`.
+    pub fn leaf_hash(data: &[u8]) -> Hash {
+        H::hashv(&[&[0x00], data])
+    }
+
+    /// Hash of an interior node, `HASH(0x01 || left || right)`.
+    pub fn node_hash(left: &Hash, right: &Hash) -> Hash {
+        H::hashv(&[&[0x01], left, right])
+    }
+
+    /// Number of leaves in the log.
+    pub fn size(&self) -> u64 {
+        self.nodes[0].len() as u64
+    }
+
+    /// Appends a leaf with the given data. Returns its index.
+    pub fn append(&mut self, data: &[u8]) -> u64 {
+        let index = self.size();
+
+        // Store roots of subtrees completed by the leaf.
+        let mut node = Self::leaf_hash(data);
+        let mut level = 0;
+        loop {
+            if level == self.nodes.len() {
+                self.nodes.push(Vec::new());
+            }
+            let nodes = &mut self.nodes[level];
+            nodes.push(node);
+            if !nodes.len().is_multiple_of(2) {
+                break;
+            }
+            node = Self::node_hash(&nodes[nodes.len() - 2], &nodes[nodes.len() - 1]);
+            level += 1;
+        }
+
+        index
+    }
+
+    pub fn root(&self) -> Hash {
+        self.mth(0, self.nodes[0].len())
+    }
+
+    /// Returns the root of the log when it had `size` leaves.
+    pub fn root_at(&self, size: u64) -> Option<Hash> {
+        (size <= self.size()).then(|| self.mth(0, size as usize))
+    }
+
+    /// Returns the audit path of the leaf under the given index in the log
+    /// with `size` leaves.
+    pub fn audit_path(&self, index: u64, size: u64) -> Option<Vec<Hash>> {
+        if index >= size || size > self.size() {
+            return None;
+        }
+
+        let mut path = vec![];
+        self.path(index as usize, 0, size as usize, &mut path);
+        Some(path)
+    }
+
+    /// Returns the consistency proof between the log with `old_size` leaves
+    /// and the log with `new_size` leaves.
+    pub fn consistency_proof(&self, old_size: u64, new_size: u64) -> Option<Vec<Hash>> {
+        if old_size == 0 || old_size > new_size || new_size > self.size() {
+            return None;
+        }
+
+        let mut proof = vec![];
+        if old_size < new_size {
+            self.subproof(old_size as usize, 0, new_size as usize, true, &mut proof);
+        }
+        Some(proof)
+    }
+
+    /// Largest power of two smaller than `n`.
+    fn split(n: usize) -> usize {
+        1 << (usize::BITS - 1 - (n - 1).leading_zeros())
+    }
+
+    /// Merkle Tree Hash of `n` leaves starting with `start`,
+    /// `MTH(D[start:start + n])`.
+    fn mth(&self, start: usize, n: usize) -> Hash {
+        match n {
+            0 => H::hash(&[]),
+            // Subtrees of the split are aligned to their size.
+            n if n.is_power_of_two() => {
+                let level = n.trailing_zeros() as usize;
+                self.nodes[level][start >> level]
+            }
+            n => {
+                let k = Self::split(n);
+                Self::node_hash(&self.mth(start, k), &self.mth(start + k, n - k))
+            }
+        }
+    }
+
+    /// Audit path, `PATH(m, D[start:start + n])`.
+    fn path(&self, m: usize, start: usize, n: usize, path: &mut Vec<Hash>) {
+        if n == 1 {
+            return;
+        }
+
+        let k = Self::split(n);
+        if m < k {
+            self.path(m, start, k, path);
+            path.push(self.mth(start + k, n - k));
+        } else {
+            self.path(m - k, start + k, n - k, path);
+            path.push(self.mth(start, k));
+        }
+    }
+
+    /// Consistency subproof, `SUBPROOF(m, D[start:start + n], b)`.
+    fn subproof(&self, m: usize, start: usize, n: usize, b: bool, proof: &mut Vec<Hash>) {
+        if m == n {
+            if !b {
+                proof.push(self.mth(start, n));
+            }
+            return;
+        }
+
+        let k = Self::split(n);
+        if m <= k {
+            self.subproof(m, start, k, b, proof);
+            proof.push(self.mth(start + k, n - k));
+        } else {
+            self.subproof(m - k, start + k, n - k, false, proof);
+            proof.push(self.mth(start, k));
+        }
+    }
+}
+
+impl<H> Default for TransparencyLog<H>
+where
+    H: Hasher,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verifies the audit path of a leaf against the root of the log with
+/// `size` leaves, as described in RFC 9162, section 2.1.3.2.
+pub fn verify_inclusion<H: Hasher>(
+    leaf_hash: Hash,
+    index: u64,
+    size: u64,
+    path: &[Hash],
+    root: Hash,
+) -> bool {
+    if index >= size {
+        return false;
+    }
+
+    let mut fnode = index;
+    let mut snode = size - 1;
+    let mut r = leaf_hash;
+    for p in path {
+        if snode == 0 {
+            return false;
+        }
+        if fnode & 1 == 1 || fnode == snode {
+            r = TransparencyLog::<H>::node_hash(p, &r);
+            while fnode & 1 == 0 && fnode != 0 {
+                fnode >>= 1;
+                snode >>= 1;
+            }
+        } else {
+            r = TransparencyLog::<H>::node_hash(&r, p);
+        }
+        fnode >>= 1;
+        snode >>= 1;
+    }
+
+    snode == 0 && r == root
+}
+
+/// Verifies the consistency proof between roots of the log with `old_size`
+/// and `new_size` leaves, as described in RFC 9162, section 2.1.4.2.
+pub fn verify_consistency<H: Hasher>(
+    old_size: u64,
+    new_size: u64,
+    old_root: Hash,
+    new_root: Hash,
+    proof: &[Hash],
+) -> bool {
+    if old_size == 0 || old_size > new_size {
+        return false;
+    }
+    if old_size == new_size {
+        return proof.is_empty() && old_root == new_root;
+    }
+    if proof.is_empty() {
+        return false;
+    }
+
+    // If the old tree is a complete subtree, its root is the first node.
+    let mut path = Vec::with_capacity(proof.len() + 1);
+    if old_size.is_power_of_two() {
+        path.push(old_root);
+    }
+    path.extend_from_slice(proof);
+
+    let mut fnode = old_size - 1;
+    let mut snode = new_size - 1;
+    while fnode & 1 == 1 {
+        fnode >>= 1;
+        snode >>= 1;
+    }
+
+    let mut fr = path[0];
+    let mut sr = path[0];
+    for c in &path[1..] {
+        if snode == 0 {
+            return false;
+        }
+        if fnode & 1 == 1 || fnode == snode {
+            fr = TransparencyLog::<H>::node_hash(c, &fr);
+            sr = TransparencyLog::<H>::node_hash(c, &sr);
+            while fnode & 1 == 0 && fnode != 0 {
+                fnode >>= 1;
+                snode >>= 1;
+            }
+        } else {
+            sr = TransparencyLog::<H>::node_hash(&sr, c);
+        }
+        fnode >>= 1;
+        snode >>= 1;
+    }
+
+    fr == old_root && sr == new_root && snode == 0
+}
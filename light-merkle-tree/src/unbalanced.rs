@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+use crate::{
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+    HASH_LEN,
+};
+
+/// Merkle tree over exactly the given leaves, without padding with zero
+/// bytes.
+///
+/// The last node of a level with an odd number of nodes is promoted to the
+/// next level as is, so the tree is left-balanced: the left subtree of every
+/// node is complete. Proofs are shorter than `height` when the leaf is on
+/// the path of promoted nodes. Only [`hash_pair`](MerkleTreeConfig::hash_pair)
+/// is used from the config.
+pub struct UnbalancedMerkleTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    /// Nodes level by level, starting with leaves and ending with the root.
+    levels: Vec<Vec<Hash>>,
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<H, C> UnbalancedMerkleTree<H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub fn new(leaves: &[[u8; HASH_LEN]]) -> Self {
+        assert!(!leaves.is_empty());
+
+        let mut levels = vec![leaves.to_vec()];
+        while levels[levels.len() - 1].len() > 1 {
+            let nodes = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => C::hash_pair::<H>(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(nodes);
+        }
+
+        UnbalancedMerkleTree {
+            levels,
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    pub fn leaves(&self) -> &[[u8; HASH_LEN]] {
+        &self.levels[0]
+    }
+
+    pub fn root(&self) -> Hash {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns siblings of the leaf under the given index, starting from the
+    /// bottom. Levels where the path goes through a promoted node have no
+    /// sibling.
+    pub fn proof(&self, index: usize) -> Option<Vec<Hash>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut index = index;
+        let mut proof = vec![];
+        for nodes in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = nodes.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Verifies the proof of the leaf under the given index in a tree with `len`
+/// leaves.
+pub fn verify_proof<H, C>(
+    leaf: [u8; HASH_LEN],
+    index: usize,
+    len: usize,
+    proof: &[Hash],
+    root: Hash,
+) -> bool
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    if index >= len {
+        return false;
+    }
+
+    let mut node = leaf;
+    let mut index = index;
+    let mut len = len;
+    let mut siblings = proof.iter();
+    while len > 1 {
+        // The last node of an odd level is promoted.
+        if index != len - 1 || !index.is_multiple_of(2) {
+            let sibling = match siblings.next() {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            node = if index.is_multiple_of(2) {
+                C::hash_pair::<H>(&node, sibling)
+            } else {
+                C::hash_pair::<H>(sibling, &node)
+            };
+        }
+        index /= 2;
+        len = len.div_ceil(2);
+    }
+
+    siblings.next().is_none() && node == root
+}
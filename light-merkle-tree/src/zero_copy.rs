@@ -0,0 +1,178 @@
+use std::{marker::PhantomData, mem};
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    append_to_filled_subtrees,
+    config::MerkleTreeConfig,
+    hasher::{Hash, Hasher},
+    initial_root, DATA_LEN, HASH_LEN, MAX_HEIGHT,
+};
+
+/// Header of a [`MerkleTreeZeroCopy`](MerkleTreeZeroCopy) buffer. It's
+/// followed by `height` filled subtrees and `roots_size` roots.
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MerkleTreeHeader {
+    /// Height of the Merkle tree.
+    pub height: u64,
+    /// Number of roots kept in the root history.
+    pub roots_size: u64,
+    /// Next index to insert a leaf.
+    pub next_index: u64,
+    /// Current index of the root.
+    pub current_root_index: u64,
+}
+
+/// Merkle tree with height and root history size chosen at runtime, which
+/// operates directly on a byte buffer (e.g. data of a Solana account).
+///
+/// Layout of the buffer:
+///
+/// | Field             | Size                    |
+/// |-------------------|-------------------------|
+/// | header            | 32                      |
+/// | `filled_subtrees` | `height * HASH_LEN`     |
+/// | `roots`           | `roots_size * HASH_LEN` |
+///
+/// The buffer has to be aligned to 8 bytes.
+pub struct MerkleTreeZeroCopy<'a, H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    pub header: &'a mut MerkleTreeHeader,
+    /// Subtree hashes.
+    pub filled_subtrees: &'a mut [[u8; HASH_LEN]],
+    /// Full history of roots of the Merkle tree (the one under
+    /// `current_root_index` is the current one).
+    pub roots: &'a mut [[u8; HASH_LEN]],
+
+    hasher: PhantomData<H>,
+    config: PhantomData<C>,
+}
+
+impl<'a, H, C> MerkleTreeZeroCopy<'a, H, C>
+where
+    H: Hasher,
+    C: MerkleTreeConfig,
+{
+    fn check_height(height: usize) {
+        assert!(height > 0);
+        assert!(height <= MAX_HEIGHT);
+    }
+
+    fn check_roots_size(roots_size: usize) {
+        assert!(roots_size > 0);
+    }
+
+    /// Returns the size of the buffer required to store a Merkle tree with
+    /// the given height and root history size.
+    pub fn size_in_bytes(height: usize, roots_size: usize) -> usize {
+        mem::size_of::<MerkleTreeHeader>() + (height + roots_size) * HASH_LEN
+    }
+
+    fn split(bytes: &'a mut [u8], height: usize, roots_size: usize) -> Self {
+        assert_eq!(bytes.len(), Self::size_in_bytes(height, roots_size));
+
+        let (header, bytes) = bytes.split_at_mut(mem::size_of::<MerkleTreeHeader>());
+        let (filled_subtrees, roots) = bytes.split_at_mut(height * HASH_LEN);
+
+        MerkleTreeZeroCopy {
+            header: bytemuck::from_bytes_mut(header),
+            filled_subtrees: bytemuck::cast_slice_mut(filled_subtrees),
+            roots: bytemuck::cast_slice_mut(roots),
+            hasher: PhantomData,
+            config: PhantomData,
+        }
+    }
+
+    /// Interprets the given buffer, previously initialized with
+    /// [`init`](MerkleTreeZeroCopy::init), as a Merkle tree.
+    ///
+    /// Panics if the header is malformed.
+    pub fn from_bytes(bytes: &'a mut [u8]) -> Self {
+        assert!(bytes.len() >= mem::size_of::<MerkleTreeHeader>());
+
+        let header: &MerkleTreeHeader =
+            bytemuck::from_bytes(&bytes[..mem::size_of::<MerkleTreeHeader>()]);
+        let height = header.height as usize;
+        let roots_size = header.roots_size as usize;
+
+        Self::check_height(height);
+        Self::check_roots_size(roots_size);
+        assert!(header.current_root_index < header.roots_size);
+        assert!(header.next_index <= 1 << height);
+
+        Self::split(bytes, height, roots_size)
+    }
+
+    /// Initialize the Merkle tree in the given buffer with subtrees and roots
+    /// based on the given height.
+    pub fn init(bytes: &'a mut [u8], height: usize, roots_size: usize) -> Self {
+        Self::check_height(height);
+        Self::check_roots_size(roots_size);
+
+        let merkle_tree = Self::split(bytes, height, roots_size);
+
+        *merkle_tree.header = MerkleTreeHeader {
+            height: height as u64,
+            roots_size: roots_size as u64,
+            next_index: 0,
+            current_root_index: 0,
+        };
+        merkle_tree
+            .filled_subtrees
+            .copy_from_slice(&C::ZERO_BYTES[..height]);
+        merkle_tree.roots.fill([0; HASH_LEN]);
+        merkle_tree.roots[0] = initial_root::<H, C>(height);
+
+        merkle_tree
+    }
+
+    pub fn hash(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) -> Hash {
+        C::hash_pair::<H>(&leaf1, &leaf2)
+    }
+
+    pub fn insert(&mut self, leaf1: [u8; DATA_LEN], leaf2: [u8; DATA_LEN]) {
+        // Check if next index doesn't exceed the Merkle tree capacity.
+        assert_ne!(self.header.next_index, 2u64.pow(self.header.height as u32));
+
+        let hash = self.hash(leaf1, leaf2);
+        let root = append_to_filled_subtrees::<H, C>(
+            self.filled_subtrees,
+            1,
+            self.header.next_index / 2,
+            hash,
+        );
+
+        self.header.current_root_index =
+            (self.header.current_root_index + 1) % self.header.roots_size;
+        self.header.next_index += 2;
+        self.roots[self.header.current_root_index as usize] =
+            C::mix_root::<H>(&root, self.header.next_index);
+    }
+
+    /// Checks whether the given root is one of the roots kept in the root
+    /// history.
+    ///
+    /// Unlike [`MerkleTree::is_known_root`](crate::MerkleTree::is_known_root),
+    /// which scans only slots up to `current_root_index`, the whole ring is
+    /// scanned, so roots remain known after the history wraps around. Slots
+    /// which were never written are zeroed, so the zero root is rejected.
+    pub fn is_known_root(&self, root: [u8; HASH_LEN]) -> bool {
+        if root == [0; HASH_LEN] {
+            return false;
+        }
+
+        let roots_size = self.header.roots_size as usize;
+        let current_root_index = self.header.current_root_index as usize;
+        (0..roots_size)
+            .map(|i| (current_root_index + roots_size - i) % roots_size)
+            .any(|i| self.roots[i] == root)
+    }
+
+    pub fn last_root(&self) -> [u8; HASH_LEN] {
+        self.roots[self.header.current_root_index as usize]
+    }
+}
@@ -1,57 +1,184 @@
-#[cfg(feature = "solana")]
-use core::mem;
-
-#[cfg(feature = "solana")]
-use light_merkle_tree::HashFunction;
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use bytemuck::Zeroable;
 use light_merkle_tree::{
+    bitcoin::{self, BlockHeader},
     config,
+    config::MerkleTreeConfig,
     constants::{self},
-    hasher::Sha256,
+    frontier::Frontier,
+    hasher::{Blake3, Hasher, Keccak, MimcSponge, Poseidon, Sha256},
+    incremental_root::IncrementalRoot,
+    incremental_witness::IncrementalWitness,
+    kary::{self, KaryFrontier, KaryMerkleTree},
+    mixed_root::{self, mix_in_length, verify_proof},
+    mmr::{self, Mmr, MmrState},
+    pruned::PrunedMerkleTree,
+    range,
+    sum::{self, MerkleSumTree, SumNode},
+    transparency::{verify_consistency, verify_inclusion, TransparencyLog},
+    unbalanced::{self, UnbalancedMerkleTree},
+    zero_copy::MerkleTreeZeroCopy,
     MerkleTree,
 };
 
-#[cfg(feature = "solana")]
-mod test_config {
-    use anchor_lang::prelude::*;
-
-    use super::*;
+use config::{
+    Blake3MerkleTreeConfig, Blake3ZeroMerkleTreeConfig, DepositContractMerkleTreeConfig,
+    KeccakMerkleTreeConfig, KeccakSortedMerkleTreeConfig, KeccakZeroMerkleTreeConfig,
+    PoseidonMerkleTreeConfig, PoseidonZeroMerkleTreeConfig, Sha256MerkleTreeConfig,
+    Sha256ZeroMerkleTreeConfig, TornadoMerkleTreeConfig,
+};
 
-    pub(crate) struct Sha256MerkleTreeConfig;
+#[test]
+fn test_sha256() {
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(3);
 
-    impl config::MerkleTreeConfig for Sha256MerkleTreeConfig {
-        const ZERO_BYTES: constants::ZeroBytes = constants::sha256::ZERO_BYTES;
-        const PROGRAM_ID: Pubkey = Pubkey::new_from_array([0u8; 32]);
-    }
+    let h = merkle_tree.hash([1; 32], [1; 32]);
+    let h = merkle_tree.hash(h, h);
+    assert_eq!(h, constants::sha256::ZERO_BYTES[0]);
 }
 
 #[test]
-fn test_sha256() {
-    #[cfg(feature = "solana")]
-    let mut merkle_tree = {
-        let mut merkle_tree: MerkleTree<Sha256, test_config::Sha256MerkleTreeConfig> =
-            unsafe { mem::zeroed() };
-        merkle_tree.init(3, HashFunction::Sha256);
-        merkle_tree
-    };
-    #[cfg(not(feature = "solana"))]
-    let mut merkle_tree = MerkleTree::<Sha256, config::Sha256MerkleTreeConfig>::new(3);
+fn test_poseidon() {
+    let mut merkle_tree = MerkleTree::<Poseidon, PoseidonMerkleTreeConfig>::new(3);
 
     let h = merkle_tree.hash([1; 32], [1; 32]);
     let h = merkle_tree.hash(h, h);
-    assert_eq!(h, constants::sha256::ZERO_BYTES[0]);
+    assert_eq!(h, constants::poseidon::ZERO_BYTES[0]);
+
+    // Inputs have to be smaller than the BN254 modulus.
+    assert!(Poseidon::is_valid_input(&[1; 32]));
+    assert!(Poseidon::is_valid_input(
+        &constants::poseidon::ZERO_BYTES[0]
+    ));
+    assert!(!Poseidon::is_valid_input(&[0xff; 32]));
+    assert!(Sha256::is_valid_input(&[0xff; 32]));
+}
+
+fn check_zero_bytes<H: Hasher, C: MerkleTreeConfig>() {
+    assert_eq!(constants::zero_bytes::<H>(C::EMPTY_LEAF), C::ZERO_BYTES);
+}
+
+#[test]
+fn test_zero_bytes_from_empty_leaf() {
+    check_zero_bytes::<Sha256, Sha256MerkleTreeConfig>();
+    check_zero_bytes::<Keccak, KeccakMerkleTreeConfig>();
+    check_zero_bytes::<Blake3, Blake3MerkleTreeConfig>();
+    check_zero_bytes::<Poseidon, PoseidonMerkleTreeConfig>();
+    check_zero_bytes::<Sha256, Sha256ZeroMerkleTreeConfig>();
+    check_zero_bytes::<Keccak, KeccakZeroMerkleTreeConfig>();
+    check_zero_bytes::<Blake3, Blake3ZeroMerkleTreeConfig>();
+    check_zero_bytes::<Poseidon, PoseidonZeroMerkleTreeConfig>();
+    check_zero_bytes::<Sha256, DepositContractMerkleTreeConfig>();
+    check_zero_bytes::<Keccak, KeccakSortedMerkleTreeConfig>();
+    check_zero_bytes::<MimcSponge, TornadoMerkleTreeConfig>();
+}
+
+/// Checks that all representations of an empty tree have the same root,
+/// the empty node on the given level.
+fn check_empty_root<C: MerkleTreeConfig>(height: usize, level: usize) {
+    let merkle_tree = MerkleTree::<Sha256, C>::new(height);
+    assert_eq!(merkle_tree.last_root(), C::ZERO_BYTES[level]);
+
+    let size = MerkleTreeZeroCopy::<Sha256, C>::size_in_bytes(height, 3);
+    let mut buf = vec![0u64; size / 8];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut buf);
+    let merkle_tree_zero_copy = MerkleTreeZeroCopy::<Sha256, C>::init(bytes, height, 3);
+    assert_eq!(merkle_tree_zero_copy.last_root(), merkle_tree.last_root());
+
+    assert_eq!(
+        IncrementalRoot::<Sha256, C>::new(height).root(),
+        merkle_tree.last_root()
+    );
+    assert_eq!(
+        Frontier::<Sha256, C>::new(height).root(),
+        merkle_tree.last_root()
+    );
+    assert_eq!(
+        PrunedMerkleTree::<Sha256, C>::new(height, 0).root(),
+        merkle_tree.last_root()
+    );
+}
+
+#[test]
+fn test_empty_root() {
+    // Root of an empty tree of height 5 in the Ethereum deposit contract
+    // (before mixing in the number of deposits).
+    assert_eq!(
+        hex::encode(MerkleTree::<Sha256, Sha256ZeroMerkleTreeConfig>::new(5).last_root()),
+        "9efde052aa15429fae05bad4d0b1d7c64da64d03d7a1854a588c2cb8430c0d30"
+    );
+    // Empty node on level 5 of SPL account compression.
+    assert_eq!(
+        hex::encode(MerkleTree::<Keccak, KeccakZeroMerkleTreeConfig>::new(5).last_root()),
+        "0eb01ebfc9ed27500cd4dfc979272d1f0913cc9f66540d7e8005811109e1cf2d"
+    );
+    // Root of an empty Poseidon tree of depth 5 with a zero leaf, as in
+    // Semaphore (big-endian).
+    let mut root = MerkleTree::<Poseidon, PoseidonZeroMerkleTreeConfig>::new(5).last_root();
+    root.reverse();
+    assert_eq!(
+        hex::encode(root),
+        "2b94cf5e8746b3f5c9631f4c5df32907a699c58c94b2ad4d7b5cec1639183f55"
+    );
+
+    // Configs without `FULL_HEIGHT_EMPTY_ROOT` keep the empty node one level
+    // below the root.
+    for height in 1..=4 {
+        check_empty_root::<Sha256MerkleTreeConfig>(height, height - 1);
+        check_empty_root::<Sha256ZeroMerkleTreeConfig>(height, height);
+    }
+}
+
+#[test]
+fn test_tornado() {
+    // `zeros(i)` of Tornado Cash `MerkleTreeWithHistory`, big-endian.
+    let zeros = [
+        "2fe54c60d3acabf3343a35b6eba15db4821b340f76e741e2249685ed4899af6c",
+        "256a6135777eee2fd26f54b8b7037a25439d5235caee224154186d2b8a52e31d",
+        "1151949895e82ab19924de92c40a3d6f7bcb60d92b00504b8199613683f0c200",
+        "20121ee811489ff8d61f09fb89e313f14959a0f28bb428a20dba6b0b068b3bdb",
+        "0a89ca6ffa14cc462cfedb842c30ed221a50a3d6bf022a6a57dc82ab24c157c9",
+    ];
+    for (zero, expected) in TornadoMerkleTreeConfig::ZERO_BYTES.iter().zip(zeros) {
+        assert_eq!(hex::encode(zero), expected);
+    }
+
+    // Tornado Cash sets the root of an empty tree to `zeros(levels - 1)`.
+    let merkle_tree = MerkleTree::<MimcSponge, TornadoMerkleTreeConfig>::new(5);
+    assert_eq!(hex::encode(merkle_tree.last_root()), zeros[4]);
+
+    // Root of an empty Tornado Cash pool, which has 20 levels.
+    let zero_18 = TornadoMerkleTreeConfig::ZERO_BYTES[18];
+    let zero_19 = MimcSponge::hashv(&[&zero_18, &zero_18]);
+    assert_eq!(
+        hex::encode(zero_19),
+        "198622acbd783d1b0d9064105b1fc8e4d8889de95c4c519b3f635809fe6afc05"
+    );
+
+    // Inputs have to be smaller than the BN254 modulus.
+    assert!(MimcSponge::is_valid_input(&zero_19));
+    assert!(!MimcSponge::is_valid_input(&[0xff; 32]));
+}
+
+#[test]
+fn test_merkle_tree_init() {
+    let merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(3);
+
+    let mut merkle_tree_zeroed = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::zeroed();
+    merkle_tree_zeroed.init(3);
+
+    assert_eq!(merkle_tree.height, merkle_tree_zeroed.height);
+    assert_eq!(
+        merkle_tree.filled_subtrees,
+        merkle_tree_zeroed.filled_subtrees
+    );
+    assert_eq!(merkle_tree.roots, merkle_tree_zeroed.roots);
+    assert_eq!(merkle_tree.last_root(), merkle_tree_zeroed.last_root());
 }
 
 #[test]
 fn test_merkle_tree_insert() {
-    #[cfg(feature = "solana")]
-    let mut merkle_tree = {
-        let mut merkle_tree: MerkleTree<Sha256, test_config::Sha256MerkleTreeConfig> =
-            unsafe { mem::zeroed() };
-        merkle_tree.init(3, HashFunction::Sha256);
-        merkle_tree
-    };
-    #[cfg(not(feature = "solana"))]
-    let mut merkle_tree = MerkleTree::<Sha256, config::Sha256MerkleTreeConfig>::new(3);
+    let mut merkle_tree = MerkleTree::<Sha256, Sha256MerkleTreeConfig>::new(3);
 
     let h1 = merkle_tree.hash([1; 32], [2; 32]);
     let h2 = merkle_tree.hash(h1, constants::sha256::ZERO_BYTES[1]);
@@ -78,3 +205,1478 @@ fn test_merkle_tree_insert() {
         ]
     )
 }
+
+#[test]
+fn test_merkle_tree_zero_copy() {
+    type Config = Sha256MerkleTreeConfig;
+
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(4);
+
+    // Back the buffer with `u64`s to satisfy the alignment of the header.
+    let size = MerkleTreeZeroCopy::<Sha256, Config>::size_in_bytes(4, 3);
+    let mut buf = vec![0u64; size / 8];
+    let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut buf);
+    assert_eq!(bytes.len(), size);
+
+    let mut merkle_tree_zero_copy = MerkleTreeZeroCopy::<Sha256, Config>::init(bytes, 4, 3);
+    assert_eq!(merkle_tree_zero_copy.last_root(), merkle_tree.last_root());
+
+    let mut roots = vec![];
+    for i in 0..8u8 {
+        merkle_tree.insert([i; 32], [i + 1; 32]);
+        merkle_tree_zero_copy.insert([i; 32], [i + 1; 32]);
+
+        assert_eq!(merkle_tree_zero_copy.last_root(), merkle_tree.last_root());
+        assert_eq!(
+            merkle_tree_zero_copy.filled_subtrees,
+            &merkle_tree.filled_subtrees[..4]
+        );
+        roots.push(merkle_tree.last_root());
+    }
+
+    // Only the last 3 roots are kept in the history.
+    let merkle_tree_zero_copy = MerkleTreeZeroCopy::<Sha256, Config>::from_bytes(bytes);
+    assert_eq!(merkle_tree_zero_copy.header.next_index, 16);
+    for root in &roots[..5] {
+        assert!(!merkle_tree_zero_copy.is_known_root(*root));
+    }
+    for root in &roots[5..] {
+        assert!(merkle_tree_zero_copy.is_known_root(*root));
+    }
+    // Unused slots of the history are zeroed, but the zero root isn't known.
+    assert!(!merkle_tree_zero_copy.is_known_root([0; 32]));
+
+    // Malformed headers are rejected.
+    for (current_root_index, next_index) in [(3, 16), (2, 18)] {
+        let mut buf = buf.clone();
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut buf);
+        let merkle_tree_zero_copy = MerkleTreeZeroCopy::<Sha256, Config>::from_bytes(bytes);
+        merkle_tree_zero_copy.header.current_root_index = current_root_index;
+        merkle_tree_zero_copy.header.next_index = next_index;
+        assert!(std::panic::catch_unwind(move || {
+            MerkleTreeZeroCopy::<Sha256, Config>::from_bytes(bytemuck::cast_slice_mut(&mut buf));
+        })
+        .is_err());
+    }
+}
+
+#[test]
+fn test_merkle_tree_from_leaves() {
+    type Tree = MerkleTree<Sha256, Sha256MerkleTreeConfig>;
+
+    let height = 6;
+    let leaves: Vec<[u8; 32]> = (0..64u8).map(|i| [i; 32]).collect();
+
+    let mut merkle_tree = Tree::new(height);
+    for len in (0..=leaves.len()).step_by(2) {
+        if len > 0 {
+            merkle_tree.insert(leaves[len - 2], leaves[len - 1]);
+        }
+
+        let (levels, merkle_tree_from_leaves) = Tree::from_leaves(height, &leaves[..len]);
+        assert_eq!(levels.len(), height + 1);
+        assert_eq!(levels[0], &leaves[..len]);
+        if len > 0 {
+            assert_eq!(levels[height], vec![merkle_tree.last_root()]);
+        }
+
+        assert_eq!(merkle_tree_from_leaves.height, merkle_tree.height);
+        assert_eq!(
+            merkle_tree_from_leaves.filled_subtrees,
+            merkle_tree.filled_subtrees
+        );
+        assert_eq!(merkle_tree_from_leaves.roots, merkle_tree.roots);
+        assert_eq!(merkle_tree_from_leaves.next_index, merkle_tree.next_index);
+        assert_eq!(
+            merkle_tree_from_leaves.current_root_index,
+            merkle_tree.current_root_index
+        );
+    }
+}
+
+#[test]
+fn test_incremental_root() {
+    type Config = Sha256MerkleTreeConfig;
+
+    let height = 5;
+    let leaves: Vec<[u8; 32]> = (0..32u8).map(|i| [i; 32]).collect();
+
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    assert_eq!(
+        IncrementalRoot::<Sha256, Config>::compute(height, []),
+        merkle_tree.last_root()
+    );
+
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        let len = pair[0][0] as usize + 2;
+
+        assert_eq!(
+            IncrementalRoot::<Sha256, Config>::compute(height, leaves[..len].iter().copied()),
+            merkle_tree.last_root()
+        );
+
+        // An odd number of leaves is padded with the empty leaf.
+        let mut padded = MerkleTree::<Sha256, Config>::new(height);
+        for pair in leaves[..len - 1].chunks(2) {
+            padded.insert(pair[0], pair.get(1).copied().unwrap_or(Config::EMPTY_LEAF));
+        }
+        assert_eq!(
+            IncrementalRoot::<Sha256, Config>::compute(height, leaves[..len - 1].iter().copied()),
+            padded.last_root()
+        );
+    }
+
+    // Trees higher than `MAX_HEIGHT` extend the zero bytes. All leaves are in
+    // the leftmost subtree of height 18.
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(18);
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+    }
+    let zero_bytes = constants::extended_zero_bytes::<Sha256, Config>(24);
+    let root = (18..24).fold(merkle_tree.last_root(), |node, level| {
+        Sha256::hashv(&[&node, &zero_bytes[level]])
+    });
+    assert_eq!(
+        IncrementalRoot::<Sha256, Config>::compute(24, leaves.iter().copied()),
+        root
+    );
+    assert_eq!(
+        IncrementalRoot::<Sha256, Config>::new(24).root(),
+        zero_bytes[23]
+    );
+    assert_eq!(zero_bytes[..19], Config::ZERO_BYTES);
+}
+
+#[test]
+fn test_incremental_witness() {
+    type Config = Sha256MerkleTreeConfig;
+
+    let height = 5;
+    let leaves: Vec<[u8; 32]> = (0..32u8).map(|i| [i; 32]).collect();
+
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    let mut witnesses: Vec<IncrementalWitness<Sha256, Config>> = vec![];
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        for witness in witnesses.iter_mut() {
+            witness.insert(pair[0], pair[1]);
+        }
+
+        // Witness leaves of every third insert.
+        let index = merkle_tree.next_index - 2;
+        if index.is_multiple_of(6) {
+            witnesses.push(IncrementalWitness::from_tree(
+                &merkle_tree,
+                pair[0],
+                pair[1],
+                index,
+            ));
+            witnesses.push(IncrementalWitness::from_tree(
+                &merkle_tree,
+                pair[0],
+                pair[1],
+                index + 1,
+            ));
+        }
+
+        for witness in witnesses.iter() {
+            assert_eq!(witness.leaf(), leaves[witness.index() as usize]);
+            assert_eq!(witness.path().len(), height);
+            assert_eq!(witness.root(), merkle_tree.last_root());
+        }
+    }
+    assert_eq!(witnesses.len(), 12);
+}
+
+#[test]
+fn test_pruned_merkle_tree() {
+    type Config = Sha256MerkleTreeConfig;
+
+    let height = 5;
+    let leaves: Vec<[u8; 32]> = (0..32u8).map(|i| [i; 32]).collect();
+
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    let mut pruned = PrunedMerkleTree::<Sha256, Config>::new(height, 10);
+    assert_eq!(pruned.root(), merkle_tree.last_root());
+    assert_eq!(pruned.mark(), None);
+
+    let check_proofs = |pruned: &PrunedMerkleTree<Sha256, Config>| {
+        for index in pruned.marked() {
+            let witness = pruned.witness(index).unwrap();
+            assert_eq!(witness.leaf(), leaves[index as usize]);
+            assert_eq!(witness.root(), pruned.root());
+        }
+    };
+
+    for pair in leaves[..20].chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        pruned.append(pair[0]);
+        if pair[0][0] % 3 == 0 {
+            pruned.mark();
+        }
+        pruned.append(pair[1]);
+        if pair[1][0] % 5 == 0 {
+            pruned.mark();
+        }
+
+        assert_eq!(pruned.root(), merkle_tree.last_root());
+        check_proofs(&pruned);
+    }
+    assert_eq!(
+        pruned.marked().collect::<Vec<_>>(),
+        vec![0, 5, 6, 12, 15, 18]
+    );
+
+    assert!(pruned.unmark(5));
+    assert!(!pruned.unmark(5));
+    let root = pruned.root();
+
+    // Appends, marks and unmarks after the checkpoint are reverted.
+    pruned.checkpoint();
+    for leaf in &leaves[20..27] {
+        pruned.append(*leaf);
+        pruned.mark();
+        check_proofs(&pruned);
+    }
+    assert!(pruned.unmark(0));
+    assert!(pruned.rewind());
+    assert!(!pruned.rewind());
+
+    assert_eq!(pruned.next_index(), 20);
+    assert_eq!(pruned.root(), root);
+    assert_eq!(pruned.marked().collect::<Vec<_>>(), vec![0, 6, 12, 15, 18]);
+    check_proofs(&pruned);
+
+    for pair in leaves[20..].chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        pruned.insert(pair[0], pair[1]);
+        assert_eq!(pruned.root(), merkle_tree.last_root());
+        check_proofs(&pruned);
+    }
+}
+
+#[test]
+fn test_frontier() {
+    type Config = Sha256MerkleTreeConfig;
+
+    let height = 5;
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    let mut frontier = Frontier::<Sha256, Config>::new(height);
+    assert_eq!(frontier.root(), merkle_tree.last_root());
+
+    for i in 0..16u8 {
+        merkle_tree.insert([i; 32], [i + 1; 32]);
+        frontier.append([i; 32], [i + 1; 32]);
+        assert_eq!(frontier.root(), merkle_tree.last_root());
+        assert_eq!(frontier.filled_subtrees, merkle_tree.filled_subtrees);
+        assert_eq!(frontier.next_index, merkle_tree.next_index);
+
+        let bytes = frontier.to_bytes();
+        assert_eq!(
+            bytes.len(),
+            Frontier::<Sha256, Config>::size_in_bytes(height)
+        );
+        let decoded = Frontier::<Sha256, Config>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.height, frontier.height);
+        assert_eq!(decoded.filled_subtrees, frontier.filled_subtrees);
+        assert_eq!(decoded.next_index, frontier.next_index);
+        assert_eq!(decoded.root(), frontier.root());
+
+        let decoded =
+            Frontier::<Sha256, Config>::try_from_slice(&frontier.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.height, frontier.height);
+        assert_eq!(decoded.filled_subtrees, frontier.filled_subtrees);
+        assert_eq!(decoded.next_index, frontier.next_index);
+        assert_eq!(decoded.root(), frontier.root());
+    }
+
+    // Appends can be resumed from a frontier of an existing tree.
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    for i in 0..5u8 {
+        merkle_tree.insert([i; 32], [i; 32]);
+    }
+    let mut resumed = MerkleTree::from(&Frontier::from(&merkle_tree));
+    assert_eq!(resumed.last_root(), merkle_tree.last_root());
+    // Only the root of the frontier is known, not the empty root.
+    assert!(resumed.is_known_root(merkle_tree.last_root()));
+    assert!(!resumed.is_known_root(MerkleTree::<Sha256, Config>::new(height).last_root()));
+    for i in 5..16u8 {
+        merkle_tree.insert([i; 32], [i; 32]);
+        resumed.insert([i; 32], [i; 32]);
+        assert_eq!(resumed.last_root(), merkle_tree.last_root());
+        assert_eq!(resumed.current_root_index, merkle_tree.current_root_index);
+    }
+
+    assert!(Frontier::<Sha256, Config>::from_bytes(&[]).is_err());
+    let mut bytes = frontier.to_bytes();
+    bytes[0] = 4;
+    assert!(Frontier::<Sha256, Config>::from_bytes(&bytes).is_err());
+    bytes[0] = 5;
+    bytes[1] = 3;
+    assert!(Frontier::<Sha256, Config>::from_bytes(&bytes).is_err());
+    assert!(Frontier::<Sha256, Config>::from_bytes(&bytes[..5]).is_err());
+
+    // Borsh deserialization is validated the same way.
+    let bytes = frontier.try_to_vec().unwrap();
+    let malformed = |offset: usize, value: u64| {
+        let mut bytes = bytes.clone();
+        bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+        Frontier::<Sha256, Config>::try_from_slice(&bytes)
+    };
+    // Offset of `next_index`, after the height and filled subtrees.
+    let next_index_offset = 8 + 18 * 32;
+    assert!(malformed(0, 5).is_ok());
+    assert!(malformed(0, 0).is_err());
+    assert!(malformed(0, 60).is_err());
+    assert!(malformed(next_index_offset, 34).is_err());
+    assert!(malformed(next_index_offset, 3).is_err());
+    assert!(Frontier::<Sha256, Config>::try_from_slice(&bytes[..bytes.len() - 1]).is_err());
+}
+
+/// Port of `MerkleProof.processProof` from OpenZeppelin, which doesn't take
+/// the index of the leaf.
+fn process_proof_openzeppelin(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(leaf, |node, sibling| {
+        if node < *sibling {
+            Keccak::hashv(&[&node, sibling])
+        } else {
+            Keccak::hashv(&[sibling, &node])
+        }
+    })
+}
+
+#[test]
+fn test_sorted_pairs() {
+    type Config = KeccakSortedMerkleTreeConfig;
+
+    // Leaf of OpenZeppelin `StandardMerkleTree` with `["address", "uint256"]`
+    // values.
+    let leaf = |address: u8, amount: u128| {
+        let mut encoded = [0u8; 64];
+        encoded[12..32].fill(address);
+        encoded[48..].copy_from_slice(&amount.to_be_bytes());
+        Keccak::hash(&Keccak::hash(&encoded))
+    };
+
+    // Example from the README of `@openzeppelin/merkle-tree`.
+    let leaf1 = leaf(0x11, 5_000_000_000_000_000_000);
+    let leaf2 = leaf(0x22, 2_500_000_000_000_000_000);
+    let root = "d4dee0beab2d53f2cc83e567171bd2820e49898130a22622b10ead383e90bd77";
+
+    let mut merkle_tree = MerkleTree::<Keccak, Config>::new(1);
+    merkle_tree.insert(leaf1, leaf2);
+    assert_eq!(hex::encode(merkle_tree.last_root()), root);
+    // The order of leaves doesn't matter for the root.
+    let mut merkle_tree = MerkleTree::<Keccak, Config>::new(1);
+    merkle_tree.insert(leaf2, leaf1);
+    assert_eq!(hex::encode(merkle_tree.last_root()), root);
+
+    // Proofs of a bigger tree are verified without indices.
+    let height = 3;
+    let leaves: Vec<[u8; 32]> = (1..=8u8).map(|i| leaf(i, i as u128)).collect();
+    let (levels, merkle_tree) = MerkleTree::<Keccak, Config>::from_leaves(height, &leaves);
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof: Vec<[u8; 32]> = (0..height)
+            .map(|level| levels[level][(index >> level) ^ 1])
+            .collect();
+        assert_eq!(
+            process_proof_openzeppelin(*leaf, &proof),
+            merkle_tree.last_root()
+        );
+    }
+    assert_ne!(
+        merkle_tree.last_root(),
+        MerkleTree::<Keccak, KeccakZeroMerkleTreeConfig>::from_leaves(height, &leaves)
+            .1
+            .last_root()
+    );
+}
+
+/// Port of the Ethereum deposit contract (`deposit_contract.sol`) with a
+/// configurable depth, used as a reference for length-mixed roots.
+struct DepositContract {
+    branch: Vec<[u8; 32]>,
+    zero_hashes: Vec<[u8; 32]>,
+    deposit_count: u64,
+}
+
+impl DepositContract {
+    fn new(depth: usize) -> Self {
+        let mut zero_hashes = vec![[0u8; 32]; depth];
+        for height in 0..depth - 1 {
+            zero_hashes[height + 1] = Sha256::hashv(&[&zero_hashes[height], &zero_hashes[height]]);
+        }
+        DepositContract {
+            branch: vec![[0; 32]; depth],
+            zero_hashes,
+            deposit_count: 0,
+        }
+    }
+
+    fn deposit(&mut self, deposit_data_root: [u8; 32]) {
+        let mut node = deposit_data_root;
+        self.deposit_count += 1;
+        let mut size = self.deposit_count;
+        for height in 0..self.branch.len() {
+            if size & 1 == 1 {
+                self.branch[height] = node;
+                return;
+            }
+            node = Sha256::hashv(&[&self.branch[height], &node]);
+            size /= 2;
+        }
+        unreachable!();
+    }
+
+    fn get_deposit_root(&self) -> [u8; 32] {
+        let mut node = [0u8; 32];
+        let mut size = self.deposit_count;
+        for height in 0..self.branch.len() {
+            node = if size & 1 == 1 {
+                Sha256::hashv(&[&self.branch[height], &node])
+            } else {
+                Sha256::hashv(&[&node, &self.zero_hashes[height]])
+            };
+            size /= 2;
+        }
+        Sha256::hashv(&[&node, &self.deposit_count.to_le_bytes(), &[0u8; 24]])
+    }
+}
+
+#[test]
+fn test_mix_in_length() {
+    type Config = DepositContractMerkleTreeConfig;
+
+    // Root of the empty deposit contract, which has 32 levels.
+    let mut zero = constants::sha256_zero::ZERO_BYTES[18];
+    for _ in 18..32 {
+        zero = Sha256::hashv(&[&zero, &zero]);
+    }
+    assert_eq!(
+        hex::encode(mix_in_length::<Sha256>(&zero, 0)),
+        "d70a234731285c6804c2a4f56711ddb8c82c99740f207854891028af34e27e5e"
+    );
+    let mut deposit_contract = DepositContract::new(32);
+    assert_eq!(
+        hex::encode(deposit_contract.get_deposit_root()),
+        "d70a234731285c6804c2a4f56711ddb8c82c99740f207854891028af34e27e5e"
+    );
+
+    // Roots of the deposit contract after each deposit.
+    let deposits: Vec<[u8; 32]> = (0..5u8).map(|i| Sha256::hash(&[i])).collect();
+    for count in 1..=deposits.len() {
+        deposit_contract.deposit(deposits[count - 1]);
+        assert_eq!(
+            IncrementalRoot::<Sha256, Config>::compute(32, deposits[..count].to_vec()),
+            deposit_contract.get_deposit_root()
+        );
+    }
+    assert_eq!(
+        hex::encode(deposit_contract.get_deposit_root()),
+        hex::encode(mix_in_length::<Sha256>(
+            &IncrementalRoot::<Sha256, Sha256ZeroMerkleTreeConfig>::compute(32, deposits.clone()),
+            5
+        ))
+    );
+
+    // Proofs of deposits verify against the root of the deposit contract.
+    let height = 4;
+    let mut deposit_contract = DepositContract::new(height);
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    for pair in deposits[..4].chunks(2) {
+        deposit_contract.deposit(pair[0]);
+        deposit_contract.deposit(pair[1]);
+        merkle_tree.insert(pair[0], pair[1]);
+        assert_eq!(merkle_tree.last_root(), deposit_contract.get_deposit_root());
+    }
+    let (levels, _) = MerkleTree::<Sha256, Config>::from_leaves(height, &deposits[..4]);
+    for index in 0..4u64 {
+        let proof: Vec<[u8; 32]> = (0..height)
+            .map(|level| {
+                levels[level]
+                    .get(((index >> level) ^ 1) as usize)
+                    .copied()
+                    .unwrap_or(Config::ZERO_BYTES[level])
+            })
+            .collect();
+        assert!(verify_proof::<Sha256, Config>(
+            height,
+            deposits[index as usize],
+            index,
+            &proof,
+            4,
+            deposit_contract.get_deposit_root()
+        ));
+        assert!(!verify_proof::<Sha256, Config>(
+            height,
+            deposits[index as usize],
+            index,
+            &proof,
+            5,
+            deposit_contract.get_deposit_root()
+        ));
+    }
+
+    let height = 4;
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    let mut unmixed = MerkleTree::<Sha256, Sha256ZeroMerkleTreeConfig>::new(height);
+    let mut frontier = Frontier::<Sha256, Config>::new(height);
+    let mut pruned = PrunedMerkleTree::<Sha256, Config>::new(height, 0);
+    assert_eq!(
+        merkle_tree.last_root(),
+        mix_in_length::<Sha256>(&constants::sha256_zero::ZERO_BYTES[height], 0)
+    );
+    assert_eq!(frontier.root(), merkle_tree.last_root());
+    assert_eq!(pruned.root(), merkle_tree.last_root());
+
+    let leaves: Vec<[u8; 32]> = (0..16u8).map(|i| [i; 32]).collect();
+    let mut roots = vec![];
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        unmixed.insert(pair[0], pair[1]);
+        frontier.append(pair[0], pair[1]);
+        pruned.insert(pair[0], pair[1]);
+
+        let count = merkle_tree.next_index;
+        assert_eq!(
+            merkle_tree.last_root(),
+            mix_in_length::<Sha256>(&unmixed.last_root(), count)
+        );
+        assert_eq!(frontier.root(), merkle_tree.last_root());
+        assert_eq!(pruned.root(), merkle_tree.last_root());
+        assert_eq!(
+            IncrementalRoot::<Sha256, Config>::compute(height, leaves[..count as usize].to_vec()),
+            merkle_tree.last_root()
+        );
+        roots.push(merkle_tree.last_root());
+    }
+
+    // Proofs are valid only with the number of leaves the root commits to.
+    let (levels, _) = MerkleTree::<Sha256, Config>::from_leaves(height, &leaves[..6]);
+    let proof: Vec<[u8; 32]> = (0..height)
+        .map(|level| {
+            levels[level]
+                .get((3 >> level) ^ 1)
+                .copied()
+                .unwrap_or(constants::sha256_zero::ZERO_BYTES[level])
+        })
+        .collect();
+    assert!(verify_proof::<Sha256, Config>(
+        height, leaves[3], 3, &proof, 6, roots[2]
+    ));
+    assert!(!verify_proof::<Sha256, Config>(
+        height, leaves[3], 3, &proof, 8, roots[2]
+    ));
+    assert!(!verify_proof::<Sha256, Config>(
+        height, leaves[3], 3, &proof, 6, roots[3]
+    ));
+
+    // An inner node with a truncated proof doesn't pass as a leaf.
+    let inner = Sha256::hashv(&[&leaves[2], &leaves[3]]);
+    assert_eq!(
+        mixed_root::compute_root::<Sha256, Config>(inner, 1, &proof[1..]),
+        mixed_root::compute_root::<Sha256, Config>(leaves[3], 3, &proof)
+    );
+    assert!(!verify_proof::<Sha256, Config>(
+        height,
+        inner,
+        1,
+        &proof[1..],
+        6,
+        roots[2]
+    ));
+    assert!(!verify_proof::<Sha256, Config>(
+        height,
+        leaves[3],
+        3 + (1 << height),
+        &proof,
+        u64::MAX,
+        roots[2]
+    ));
+
+    // Paths of witnesses and proofs of the pruned tree are made of plain
+    // nodes, which aren't mixed with the number of leaves.
+    let mut merkle_tree = MerkleTree::<Sha256, Config>::new(height);
+    let mut pruned = PrunedMerkleTree::<Sha256, Config>::new(height, 0);
+    let mut witnesses: Vec<IncrementalWitness<Sha256, Config>> = vec![];
+    for pair in leaves.chunks(2) {
+        merkle_tree.insert(pair[0], pair[1]);
+        pruned.insert(pair[0], pair[1]);
+        for witness in &mut witnesses {
+            witness.insert(pair[0], pair[1]);
+        }
+        let index = merkle_tree.next_index - 2;
+        witnesses.push(IncrementalWitness::from_tree(
+            &merkle_tree,
+            pair[0],
+            pair[1],
+            index,
+        ));
+        if index.is_multiple_of(4) {
+            pruned.mark();
+        }
+
+        let count = merkle_tree.next_index;
+        let (levels, _) =
+            MerkleTree::<Sha256, Config>::from_leaves(height, &leaves[..count as usize]);
+        let expected_proof = |index: u64| -> Vec<[u8; 32]> {
+            (0..height)
+                .map(|level| {
+                    levels[level]
+                        .get(((index >> level) ^ 1) as usize)
+                        .copied()
+                        .unwrap_or(Config::ZERO_BYTES[level])
+                })
+                .collect()
+        };
+        for witness in &witnesses {
+            assert_eq!(witness.path(), expected_proof(witness.index()));
+            assert_eq!(witness.root(), merkle_tree.last_root());
+            assert!(verify_proof::<Sha256, Config>(
+                height,
+                witness.leaf(),
+                witness.index(),
+                &witness.path(),
+                count,
+                merkle_tree.last_root()
+            ));
+        }
+        for index in pruned.marked() {
+            let proof = pruned.proof(index).unwrap();
+            assert_eq!(proof, expected_proof(index));
+            assert!(verify_proof::<Sha256, Config>(
+                height,
+                leaves[index as usize],
+                index,
+                &proof,
+                count,
+                merkle_tree.last_root()
+            ));
+        }
+    }
+}
+
+/// Leaves of the RFC 6962 test vectors, used by Certificate Transparency
+/// implementations.
+const TRANSPARENCY_LEAVES: [&[u8]; 8] = [
+    b"",
+    b"\x00",
+    b"\x10",
+    b"\x20\x21",
+    b"\x30\x31",
+    b"\x40\x41\x42\x43",
+    b"\x50\x51\x52\x53\x54\x55\x56\x57",
+    b"\x60\x61\x62\x63\x64\x65\x66\x67\x68\x69\x6a\x6b\x6c\x6d\x6e\x6f",
+];
+
+#[test]
+fn test_transparency_log() {
+    type Log = TransparencyLog<Sha256>;
+
+    let roots = [
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+        "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+        "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+        "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+        "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+        "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+        "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+    ];
+
+    let mut log = Log::new();
+    assert_eq!(hex::encode(log.root()), roots[0]);
+    for (i, leaf) in TRANSPARENCY_LEAVES.iter().enumerate() {
+        assert_eq!(log.append(leaf), i as u64);
+        assert_eq!(hex::encode(log.root()), roots[i + 1]);
+    }
+
+    let encode = |proof: Vec<[u8; 32]>| proof.into_iter().map(hex::encode).collect::<Vec<_>>();
+    assert_eq!(
+        encode(log.audit_path(0, 8).unwrap()),
+        [
+            "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7",
+            "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+            "6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4",
+        ]
+    );
+    assert_eq!(
+        encode(log.consistency_proof(1, 8).unwrap()),
+        [
+            "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7",
+            "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+            "6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4",
+        ]
+    );
+    assert_eq!(
+        encode(log.consistency_proof(6, 8).unwrap()),
+        [
+            "0ebc5d3437fbe2db158b9f126a1d118e308181031d0a949f8dededebc558ef6a",
+            "ca854ea128ed050b41b35ffc1b87b8eb2bde461e9e3b5596ece6b9d5975a0ae0",
+            "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        ]
+    );
+    assert_eq!(
+        encode(log.consistency_proof(2, 5).unwrap()),
+        [
+            "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+            "bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b",
+        ]
+    );
+
+    // All audit paths and consistency proofs of the log are valid.
+    for size in 1..=8 {
+        let root = log.root_at(size).unwrap();
+        for (index, leaf) in TRANSPARENCY_LEAVES[..size as usize].iter().enumerate() {
+            let index = index as u64;
+            let path = log.audit_path(index, size).unwrap();
+            let leaf_hash = Log::leaf_hash(leaf);
+            assert!(verify_inclusion::<Sha256>(
+                leaf_hash, index, size, &path, root
+            ));
+            if size > 1 {
+                assert!(!verify_inclusion::<Sha256>(
+                    leaf_hash,
+                    index ^ 1,
+                    size,
+                    &path,
+                    root
+                ));
+            }
+            assert!(!verify_inclusion::<Sha256>(
+                Log::leaf_hash(b"other"),
+                index,
+                size,
+                &path,
+                root
+            ));
+        }
+
+        for old_size in 1..=size {
+            let old_root = log.root_at(old_size).unwrap();
+            let proof = log.consistency_proof(old_size, size).unwrap();
+            assert!(verify_consistency::<Sha256>(
+                old_size, size, old_root, root, &proof
+            ));
+            if old_size < size {
+                assert!(!verify_consistency::<Sha256>(
+                    old_size, size, root, root, &proof
+                ));
+            }
+        }
+    }
+    assert!(log.audit_path(8, 8).is_none());
+    assert!(log.consistency_proof(0, 8).is_none());
+    assert!(log.consistency_proof(5, 9).is_none());
+
+    // Roots built from cached subtrees match the recursive definition of
+    // `MTH` on bigger logs.
+    fn mth(leaves: &[[u8; 32]]) -> [u8; 32] {
+        match leaves.len() {
+            0 => Sha256::hash(&[]),
+            1 => leaves[0],
+            n => {
+                let k = 1 << (usize::BITS - 1 - (n - 1).leading_zeros());
+                Log::node_hash(&mth(&leaves[..k]), &mth(&leaves[k..]))
+            }
+        }
+    }
+    let mut log = Log::new();
+    let mut leaf_hashes = vec![];
+    for i in 0..100u8 {
+        log.append(&[i]);
+        leaf_hashes.push(Log::leaf_hash(&[i]));
+        assert_eq!(log.root(), mth(&leaf_hashes));
+    }
+    for size in [1, 37, 64, 99, 100] {
+        let root = mth(&leaf_hashes[..size as usize]);
+        assert_eq!(log.root_at(size), Some(root));
+        for index in [0, size / 2, size - 1] {
+            let path = log.audit_path(index, size).unwrap();
+            assert!(verify_inclusion::<Sha256>(
+                leaf_hashes[index as usize],
+                index,
+                size,
+                &path,
+                root
+            ));
+        }
+        let proof = log.consistency_proof(size, 100).unwrap();
+        assert!(verify_consistency::<Sha256>(
+            size,
+            100,
+            root,
+            log.root(),
+            &proof
+        ));
+    }
+}
+
+/// Decodes a hash displayed by Bitcoin tools, which reverse the byte order.
+fn bitcoin_hash(displayed: &str) -> [u8; 32] {
+    let mut hash: [u8; 32] = hex::decode(displayed).unwrap().try_into().unwrap();
+    hash.reverse();
+    hash
+}
+
+#[test]
+fn test_bitcoin_genesis_block() {
+    let header = hex::decode(
+        "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c",
+    )
+    .unwrap();
+    let header = BlockHeader::from_bytes(&header.try_into().unwrap());
+    assert_eq!(
+        header.hash(),
+        bitcoin_hash("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f")
+    );
+
+    // The only transaction is the coinbase, so it's the root.
+    let coinbase = bitcoin_hash("4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b");
+    assert_eq!(bitcoin::merkle_root(&[coinbase]), Some(header.merkle_root));
+    assert!(header.verify_txids(&[coinbase]));
+    assert_eq!(bitcoin::merkle_proof(&[coinbase], 0), Some(vec![]));
+    assert!(bitcoin::verify_proof(
+        coinbase,
+        0,
+        1,
+        &[],
+        header.merkle_root
+    ));
+    assert_eq!(bitcoin::merkle_root(&[]), None);
+}
+
+#[test]
+fn test_bitcoin_block_100000() {
+    let header = hex::decode(
+        "0100000050120119172a610421a6c3011dd330d9df07b63616c2cc1f1cd00200000000006657a9252aacd5c0b2940996ecff952228c3067cc38d4885efb5a4ac4247e9f337221b4d4c86041b0f2b5710",
+    )
+    .unwrap();
+    let header = BlockHeader::from_bytes(&header.try_into().unwrap());
+    assert_eq!(
+        header.hash(),
+        bitcoin_hash("000000000003ba27aa200b1cecaad478d2b00432346c3f1f3986da1afd33e506")
+    );
+    assert_eq!(
+        header.merkle_root,
+        bitcoin_hash("f3e94742aca4b5ef85488dc37c06c3282295ffec960994b2c0d5ac2a25a95766")
+    );
+
+    let txids = [
+        "8c14f0db3df150123e6f3dbbf30f8b955a8249b62ac1d1ff16284aefa3d06d87",
+        "fff2525b8931402dd09222c50775608f75787bd2b87e56995a7bdd30f79702c4",
+        "6359f0868171b1d194cbee1af2f16ea598ae8fad666d9b012c8ed2b79a236ec4",
+        "e9a66845e05d5abc0ad04ec80f774a7e585c6e8db975962d069a522137b80c1d",
+    ]
+    .map(bitcoin_hash);
+    assert!(header.verify_txids(&txids));
+    assert!(!header.verify_txids(&txids[..3]));
+
+    for (index, txid) in txids.iter().enumerate() {
+        let proof = bitcoin::merkle_proof(&txids, index).unwrap();
+        assert_eq!(proof.len(), 2);
+        assert!(bitcoin::verify_proof(
+            *txid,
+            index,
+            txids.len(),
+            &proof,
+            header.merkle_root
+        ));
+        assert!(!bitcoin::verify_proof(
+            *txid,
+            index ^ 1,
+            txids.len(),
+            &proof,
+            header.merkle_root
+        ));
+    }
+
+    // With an odd number of transactions, the last one is paired with
+    // itself, which makes the list with a duplicated last transaction have
+    // the same root.
+    let root = bitcoin::merkle_root(&txids[..3]).unwrap();
+    assert_eq!(
+        bitcoin::merkle_root(&[txids[0], txids[1], txids[2], txids[2]]),
+        Some(root)
+    );
+    let proof = bitcoin::merkle_proof(&txids[..3], 2).unwrap();
+    assert_eq!(proof[0], txids[2]);
+    assert!(bitcoin::verify_proof(txids[2], 2, 3, &proof, root));
+    // The duplicated transaction isn't part of the block.
+    assert!(!bitcoin::verify_proof(txids[2], 3, 3, &proof, root));
+    // The last transaction of an odd level has to be paired with itself.
+    let mut forged = proof.clone();
+    forged[0] = txids[3];
+    assert!(!bitcoin::verify_proof(txids[2], 2, 3, &forged, root));
+
+    // Inner nodes can't be proven as transactions with a shorter branch.
+    let full_proof = bitcoin::merkle_proof(&txids, 0).unwrap();
+    let inner = bitcoin::merkle_root(&txids[..2]).unwrap();
+    assert!(!bitcoin::verify_proof(
+        inner,
+        0,
+        txids.len(),
+        &full_proof[1..],
+        header.merkle_root
+    ));
+
+    // Branches of the wrong length and out-of-range indices are rejected
+    // without overflowing.
+    let long_proof = vec![txids[0]; 70];
+    assert!(!bitcoin::verify_proof(txids[2], 2, 3, &long_proof, root));
+    assert!(!bitcoin::verify_proof(
+        txids[2],
+        usize::MAX,
+        3,
+        &long_proof,
+        root
+    ));
+    assert!(!bitcoin::verify_proof(txids[2], 0, 0, &[], root));
+    assert_eq!(bitcoin::tree_depth(1), 0);
+    assert_eq!(bitcoin::tree_depth(3), 2);
+    assert_eq!(bitcoin::tree_depth(4), 2);
+    assert_eq!(bitcoin::tree_depth(5), 3);
+}
+
+#[test]
+fn test_unbalanced_merkle_tree() {
+    type Config = Sha256MerkleTreeConfig;
+    type Tree = UnbalancedMerkleTree<Sha256, Config>;
+
+    let leaves: Vec<[u8; 32]> = (0..16u8).map(|i| [i; 32]).collect();
+    let hash = |left: [u8; 32], right: [u8; 32]| Sha256::hashv(&[&left, &right]);
+
+    assert_eq!(Tree::new(&leaves[..1]).root(), leaves[0]);
+    // The fifth leaf is promoted to the root level.
+    let tree = Tree::new(&leaves[..5]);
+    assert_eq!(
+        tree.root(),
+        hash(
+            hash(hash(leaves[0], leaves[1]), hash(leaves[2], leaves[3])),
+            leaves[4]
+        )
+    );
+    assert_eq!(
+        tree.proof(4).unwrap(),
+        vec![hash(hash(leaves[0], leaves[1]), hash(leaves[2], leaves[3]))]
+    );
+    assert_eq!(tree.proof(0).unwrap()[2], leaves[4]);
+    assert_eq!(tree.proof(0).unwrap().len(), 3);
+    assert!(tree.proof(5).is_none());
+
+    // A complete tree is the same as `MerkleTree`.
+    let (_, merkle_tree) = MerkleTree::<Sha256, Config>::from_leaves(4, &leaves);
+    assert_eq!(Tree::new(&leaves).root(), merkle_tree.last_root());
+
+    for len in 1..=leaves.len() {
+        let tree = Tree::new(&leaves[..len]);
+        assert_eq!(tree.leaves(), &leaves[..len]);
+        for (index, leaf) in leaves[..len].iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert!(proof.len() <= 4);
+            assert!(unbalanced::verify_proof::<Sha256, Config>(
+                *leaf,
+                index,
+                len,
+                &proof,
+                tree.root()
+            ));
+            assert!(!unbalanced::verify_proof::<Sha256, Config>(
+                leaves[(index + 1) % 16],
+                index,
+                len,
+                &proof,
+                tree.root()
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_kary_merkle_tree() {
+    // Binary trees are the same as `MerkleTree`.
+    assert_eq!(
+        kary::zero_bytes::<Sha256, 2>(Sha256MerkleTreeConfig::EMPTY_LEAF, 19),
+        Sha256MerkleTreeConfig::ZERO_BYTES
+    );
+    let leaves: Vec<[u8; 32]> = (0..=255u8).map(|i| [i; 32]).collect();
+    for len in (2..=16).step_by(2) {
+        let (_, merkle_tree) =
+            MerkleTree::<Sha256, Sha256MerkleTreeConfig>::from_leaves(4, &leaves[..len]);
+        let tree =
+            KaryMerkleTree::<Sha256, Sha256MerkleTreeConfig, 2>::from_leaves(4, &leaves[..len]);
+        assert_eq!(tree.root(), merkle_tree.last_root());
+    }
+
+    fn check<H: Hasher, C: MerkleTreeConfig, const ARITY: usize>(
+        height: usize,
+        leaves: &[[u8; 32]],
+    ) {
+        let mut tree = KaryMerkleTree::<H, C, ARITY>::new(height);
+        let mut frontier = KaryFrontier::<H, C, ARITY>::new(height);
+        assert_eq!(frontier.root(), tree.root());
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.append(*leaf);
+            frontier.append(*leaf);
+            assert_eq!(frontier.root(), tree.root());
+            assert_eq!(frontier.next_index(), i as u64 + 1);
+        }
+        assert_eq!(
+            KaryMerkleTree::<H, C, ARITY>::from_leaves(height, leaves).root(),
+            tree.root()
+        );
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert_eq!(proof.len(), height);
+            assert!(proof.iter().all(|siblings| siblings.len() == ARITY - 1));
+            assert!(kary::verify_proof::<H, ARITY>(
+                *leaf,
+                index as u64,
+                height,
+                &proof,
+                tree.root()
+            ));
+            assert!(!kary::verify_proof::<H, ARITY>(
+                *leaf,
+                index as u64 + 1,
+                height,
+                &proof,
+                tree.root()
+            ));
+        }
+
+        // Inner nodes can't be proven as leaves with a shorter proof.
+        let proof = tree.proof(0).unwrap();
+        let inner =
+            KaryMerkleTree::<H, C, ARITY>::from_leaves(1, &leaves[..ARITY.min(leaves.len())])
+                .root();
+        assert!(!kary::verify_proof::<H, ARITY>(
+            inner,
+            0,
+            height,
+            &proof[1..],
+            tree.root()
+        ));
+        assert!(kary::verify_proof::<H, ARITY>(
+            inner,
+            0,
+            height - 1,
+            &proof[1..],
+            tree.root()
+        ));
+        assert!(tree.proof(leaves.len()).is_none());
+    }
+
+    // Arity 4 with Poseidon of width 5.
+    let zero_bytes = kary::zero_bytes::<Poseidon, 4>([0; 32], 2);
+    assert_eq!(zero_bytes[1], Poseidon::hashv(&[&[0u8; 32][..]; 4]));
+    check::<Poseidon, PoseidonMerkleTreeConfig, 4>(3, &leaves[..23]);
+    check::<Sha256, Sha256MerkleTreeConfig, 4>(3, &leaves[..64]);
+    check::<Sha256, Sha256MerkleTreeConfig, 16>(2, &leaves[..37]);
+    check::<Sha256, Sha256MerkleTreeConfig, 16>(2, &leaves);
+
+    // Poseidon with circom parameters hashes at most 12 children.
+    check::<Poseidon, PoseidonMerkleTreeConfig, 12>(2, &leaves[..29]);
+    assert!(std::panic::catch_unwind(|| {
+        KaryFrontier::<Poseidon, PoseidonMerkleTreeConfig, 16>::new(2)
+    })
+    .is_err());
+    assert!(std::panic::catch_unwind(|| {
+        KaryMerkleTree::<Poseidon, PoseidonMerkleTreeConfig, 16>::new(2)
+    })
+    .is_err());
+    assert!(std::panic::catch_unwind(|| kary::zero_bytes::<Poseidon, 16>([0; 32], 2)).is_err());
+
+    // Sorted pairs and mixing in the length aren't supported.
+    assert!(std::panic::catch_unwind(|| {
+        KaryFrontier::<Keccak, KeccakSortedMerkleTreeConfig, 4>::new(2)
+    })
+    .is_err());
+    assert!(std::panic::catch_unwind(|| {
+        KaryMerkleTree::<Sha256, DepositContractMerkleTreeConfig, 4>::new(2)
+    })
+    .is_err());
+}
+
+#[test]
+fn test_merkle_sum_tree() {
+    type Config = Sha256ZeroMerkleTreeConfig;
+    type Tree = MerkleSumTree<Sha256, Config>;
+
+    let leaves: Vec<SumNode> = [100, 250, 7, 1_000, 0, 42]
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| SumNode::new(Sha256::hash(&[i as u8]), *amount))
+        .collect();
+    let tree = Tree::from_leaves(3, &leaves).unwrap();
+    assert_eq!(tree.root().sum, 1_399);
+
+    // `left_hash || le64(left_sum) || right_hash || le64(right_sum)`
+    let parent = SumNode::parent::<Sha256>(&leaves[0], &leaves[1]).unwrap();
+    let mut preimage = vec![];
+    preimage.extend_from_slice(&leaves[0].hash);
+    preimage.extend_from_slice(&100u64.to_le_bytes());
+    preimage.extend_from_slice(&leaves[1].hash);
+    preimage.extend_from_slice(&250u64.to_le_bytes());
+    assert_eq!(parent, SumNode::new(Sha256::hash(&preimage), 350));
+
+    for (index, leaf) in leaves.iter().enumerate() {
+        let proof = tree.proof(index).unwrap();
+        assert_eq!(proof.len(), 3);
+        // Partial sums of siblings add up to the total.
+        assert_eq!(
+            leaf.sum + proof.iter().map(|sibling| sibling.sum).sum::<u64>(),
+            tree.root().sum
+        );
+        assert!(sum::verify_proof::<Sha256>(
+            *leaf,
+            index as u64,
+            3,
+            &proof,
+            tree.root()
+        ));
+
+        // A different amount or a different total is rejected.
+        let forged = SumNode::new(leaf.hash, leaf.sum + 1);
+        assert!(!sum::verify_proof::<Sha256>(
+            forged,
+            index as u64,
+            3,
+            &proof,
+            tree.root()
+        ));
+        let forged_root = SumNode::new(tree.root().hash, tree.root().sum - 1);
+        assert!(!sum::verify_proof::<Sha256>(
+            *leaf,
+            index as u64,
+            3,
+            &proof,
+            forged_root
+        ));
+    }
+    assert!(tree.proof(6).is_none());
+
+    // Inner nodes can't be proven as leaves with a shorter proof.
+    let proof = tree.proof(0).unwrap();
+    assert!(!sum::verify_proof::<Sha256>(
+        parent,
+        0,
+        3,
+        &proof[1..],
+        tree.root()
+    ));
+    assert!(sum::verify_proof::<Sha256>(
+        parent,
+        0,
+        2,
+        &proof[1..],
+        tree.root()
+    ));
+
+    // Proofs longer than the bits of the index are rejected without
+    // overflowing shifts.
+    let long_proof = vec![SumNode::new([0; 32], 0); 70];
+    assert!(!sum::verify_proof::<Sha256>(
+        tree.leaves()[0],
+        0,
+        70,
+        &long_proof,
+        tree.root()
+    ));
+    assert!(!sum::verify_proof::<Sha256>(
+        tree.leaves()[0],
+        0,
+        3,
+        &long_proof,
+        tree.root()
+    ));
+
+    // Sums are checked for overflows.
+    let leaves = [SumNode::new([1; 32], u64::MAX), SumNode::new([2; 32], 1)];
+    assert!(Tree::from_leaves(1, &leaves).is_none());
+    assert!(!sum::verify_proof::<Sha256>(
+        leaves[0],
+        0,
+        1,
+        &[leaves[1]],
+        SumNode::new([0; 32], 0)
+    ));
+}
+
+#[test]
+fn test_mmr() {
+    let leaves: Vec<[u8; 32]> = (0..37u8).map(|i| Sha256::hash(&[i])).collect();
+
+    let mut mmr = Mmr::<Sha256>::new();
+    assert_eq!(mmr.root(), None);
+    assert!(mmr.proof(0).is_none());
+
+    // Compact state kept in a zero-copy buffer follows the full range. The
+    // buffer is made of `u64`s to be aligned like accounts are.
+    let mut data = vec![0u64; std::mem::size_of::<MmrState>() / 8];
+    for (i, leaf) in leaves.iter().enumerate() {
+        assert_eq!(mmr.append(*leaf), i as u64);
+        let state = bytemuck::from_bytes_mut::<MmrState>(bytemuck::cast_slice_mut(&mut data));
+        state.append::<Sha256>(*leaf);
+        assert_eq!(state, mmr.state());
+
+        let leaf_count = i + 1;
+        assert_eq!(mmr.leaf_count(), leaf_count as u64);
+        assert_eq!(mmr.state().peaks().len(), leaf_count.count_ones() as usize);
+
+        // Peaks are roots of complete subtrees of the leaves.
+        let mut start = 0;
+        let mut peaks = vec![];
+        for height in (0..usize::BITS).rev() {
+            if leaf_count >> height & 1 == 1 {
+                let mut nodes = leaves[start..start + (1 << height)].to_vec();
+                while nodes.len() > 1 {
+                    nodes = nodes
+                        .chunks(2)
+                        .map(|pair| Sha256::hashv(&[&pair[0], &pair[1]]))
+                        .collect();
+                }
+                peaks.push(nodes[0]);
+                start += 1 << height;
+            }
+        }
+        assert_eq!(mmr.state().peaks(), peaks.as_slice());
+        assert_eq!(mmr.root(), mmr::root::<Sha256>(leaf_count as u64, &peaks));
+    }
+
+    // Bagging hashes peaks from the right, and the root commits to the
+    // number of leaves.
+    let peaks = mmr.state().peaks();
+    assert_eq!(peaks.len(), 3);
+    let bagged = Sha256::hashv(&[&peaks[0], &Sha256::hashv(&[&peaks[1], &peaks[2]])]);
+    assert_eq!(mmr::bag_peaks::<Sha256>(peaks), Some(bagged));
+    assert_eq!(
+        mmr.root(),
+        Some(Sha256::hashv(&[&37u64.to_le_bytes(), &bagged]))
+    );
+
+    let root = mmr.root().unwrap();
+    let state = bytemuck::from_bytes::<MmrState>(bytemuck::cast_slice(&data));
+    for (i, leaf) in leaves.iter().enumerate() {
+        let proof = mmr.proof(i as u64).unwrap();
+        assert!(mmr::verify_proof::<Sha256>(*leaf, &proof, root));
+        assert!(state.verify_proof::<Sha256>(*leaf, &proof));
+
+        // A wrong leaf or a wrong index is rejected.
+        assert!(!mmr::verify_proof::<Sha256>([0; 32], &proof, root));
+        let mut wrong = proof.clone();
+        wrong.leaf_index ^= 1;
+        assert!(!mmr::verify_proof::<Sha256>(*leaf, &wrong, root));
+    }
+    assert!(mmr.proof(leaves.len() as u64).is_none());
+
+    // Proofs made for an older range don't match newer peaks.
+    let proof = mmr.proof(0).unwrap();
+    mmr.append([0; 32]);
+    assert!(!mmr.state().verify_proof::<Sha256>(leaves[0], &proof));
+    assert!(mmr::verify_proof::<Sha256>(leaves[0], &proof, root));
+
+    // Inner nodes can't be proven as leaves of a smaller range, neither with
+    // multiple peaks nor with a single one.
+    let mut mmr = Mmr::<Sha256>::new();
+    for leaf in &leaves[..6] {
+        mmr.append(*leaf);
+    }
+    let peaks = mmr.state().peaks().to_vec();
+    let inner = Sha256::hashv(&[&leaves[0], &leaves[1]]);
+    let forged = mmr::MmrProof {
+        leaf_index: 0,
+        leaf_count: 3,
+        path: vec![Sha256::hashv(&[&leaves[2], &leaves[3]])],
+        peaks: peaks.clone(),
+    };
+    assert_eq!(
+        mmr::bag_peaks::<Sha256>(&forged.peaks),
+        mmr::bag_peaks::<Sha256>(&peaks)
+    );
+    assert!(!mmr::verify_proof::<Sha256>(
+        inner,
+        &forged,
+        mmr.root().unwrap()
+    ));
+
+    let mut mmr = Mmr::<Sha256>::new();
+    for leaf in &leaves[..4] {
+        mmr.append(*leaf);
+    }
+    let forged = mmr::MmrProof {
+        leaf_index: 0,
+        leaf_count: 2,
+        path: vec![Sha256::hashv(&[&leaves[2], &leaves[3]])],
+        peaks: mmr.state().peaks().to_vec(),
+    };
+    assert!(!mmr::verify_proof::<Sha256>(
+        inner,
+        &forged,
+        mmr.root().unwrap()
+    ));
+    assert!(!mmr.state().verify_proof::<Sha256>(inner, &forged));
+    assert!(mmr::verify_proof::<Sha256>(
+        leaves[0],
+        &mmr.proof(0).unwrap(),
+        mmr.root().unwrap()
+    ));
+}
+
+#[test]
+fn test_range_proof() {
+    type Tree = MerkleTree<Sha256, Sha256MerkleTreeConfig>;
+
+    let height = 5;
+    let leaves: Vec<[u8; 32]> = (0..22u8).map(|i| [i; 32]).collect();
+    let (levels, merkle_tree) = Tree::from_leaves(height, &leaves);
+    let root = merkle_tree.last_root();
+    let count = merkle_tree.next_index;
+
+    for start in 0..leaves.len() {
+        for end in start + 1..=leaves.len() {
+            let range = &leaves[start..end];
+            let proof = range::range_proof::<Sha256MerkleTreeConfig>(&levels, start, end).unwrap();
+            // Proofs have at most one sibling per level on each side.
+            assert!(proof.left.len() <= height);
+            assert!(proof.right.len() <= height);
+            assert!(range::verify_proof::<Sha256, Sha256MerkleTreeConfig>(
+                height,
+                start as u64,
+                range,
+                &proof,
+                count,
+                root
+            ));
+
+            // A tampered leaf is rejected.
+            let mut tampered = range.to_vec();
+            tampered[(end - start) / 2] = [0xff; 32];
+            assert!(!range::verify_proof::<Sha256, Sha256MerkleTreeConfig>(
+                height,
+                start as u64,
+                &tampered,
+                &proof,
+                count,
+                root
+            ));
+
+            // Proofs of a range don't prove a range shorter by a leaf.
+            if end - start > 1 {
+                assert!(!range::verify_proof::<Sha256, Sha256MerkleTreeConfig>(
+                    height,
+                    start as u64,
+                    &range[..range.len() - 1],
+                    &proof,
+                    count,
+                    root
+                ));
+            }
+        }
+    }
+
+    // A single leaf has the same siblings as its regular proof.
+    let proof = range::range_proof::<Sha256MerkleTreeConfig>(&levels, 5, 6).unwrap();
+    assert_eq!(proof.left.len() + proof.right.len(), height);
+
+    // Ranges have to be non-empty and within the leaves.
+    assert!(range::range_proof::<Sha256MerkleTreeConfig>(&levels, 3, 3).is_none());
+    assert!(range::range_proof::<Sha256MerkleTreeConfig>(&levels, 20, 23).is_none());
+    let proof = range::range_proof::<Sha256MerkleTreeConfig>(&levels, 20, 22).unwrap();
+    assert!(!range::verify_proof::<Sha256, Sha256MerkleTreeConfig>(
+        height,
+        20,
+        &leaves[20..22],
+        &proof,
+        20,
+        root
+    ));
+    assert!(!range::verify_proof::<Sha256, Sha256MerkleTreeConfig>(
+        height,
+        u64::MAX,
+        &leaves[20..22],
+        &proof,
+        count,
+        root
+    ));
+
+    // With the number of leaves mixed into the root.
+    type Config = DepositContractMerkleTreeConfig;
+    let (levels, merkle_tree) = MerkleTree::<Sha256, Config>::from_leaves(height, &leaves);
+    let proof = range::range_proof::<Config>(&levels, 3, 17).unwrap();
+    assert!(range::verify_proof::<Sha256, Config>(
+        height,
+        3,
+        &leaves[3..17],
+        &proof,
+        merkle_tree.next_index,
+        merkle_tree.last_root()
+    ));
+    assert!(!range::verify_proof::<Sha256, Config>(
+        height,
+        3,
+        &leaves[3..17],
+        &proof,
+        merkle_tree.next_index + 2,
+        merkle_tree.last_root()
+    ));
+}
+
+#[test]
+fn test_merkle_tree_append_subtree() {
+    type Tree = MerkleTree<Sha256, Sha256MerkleTreeConfig>;
+
+    let height = 6;
+    let leaves: Vec<[u8; 32]> = (0..64u8).map(|i| [i; 32]).collect();
+
+    // Aligned subtrees of different sizes, filling the whole tree.
+    let mut merkle_tree = Tree::new(height);
+    let mut start = 0;
+    for k in [1, 1, 2, 3, 1, 1, 2, 3, 5] {
+        let end = start + (1 << k);
+        let (levels, _) = Tree::from_leaves(k, &leaves[start..end]);
+        merkle_tree.append_subtree(levels[k][0], k);
+
+        // The tree is in the same state as with leaves inserted one pair
+        // at a time, apart from the root history and subtrees below level
+        // `k`, which are overwritten by the next aligned append before
+        // being read.
+        let (_, merkle_tree_from_leaves) = Tree::from_leaves(height, &leaves[..end]);
+        assert_eq!(merkle_tree.next_index, end as u64);
+        assert_eq!(
+            merkle_tree.filled_subtrees[k..],
+            merkle_tree_from_leaves.filled_subtrees[k..]
+        );
+        assert_eq!(merkle_tree.last_root(), merkle_tree_from_leaves.last_root());
+        assert!(merkle_tree.is_known_root(merkle_tree_from_leaves.last_root()));
+
+        start = end;
+    }
+    assert_eq!(start, leaves.len());
+    assert_eq!(merkle_tree.current_root_index, 9);
+
+    // A subtree with all leaves is the whole tree.
+    let mut full = Tree::new(height);
+    full.append_subtree(merkle_tree.last_root(), height);
+    assert_eq!(full.last_root(), merkle_tree.last_root());
+    assert_eq!(full.next_index, 64);
+
+    // Subtrees have to be aligned and fit in the tree.
+    let append = |next_index: u64, k: usize| {
+        std::panic::catch_unwind(move || {
+            let mut merkle_tree = Tree::new(height);
+            merkle_tree.next_index = next_index;
+            merkle_tree.append_subtree([0; 32], k);
+        })
+    };
+    assert!(append(2, 2).is_err());
+    assert!(append(4, 3).is_err());
+    assert!(append(32, 6).is_err());
+    assert!(append(64, 1).is_err());
+    assert!(append(0, 0).is_err());
+    assert!(append(8, 3).is_ok());
+}
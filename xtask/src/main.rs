@@ -1,5 +1,6 @@
 use clap::Parser;
 
+mod verify_constants;
 mod zero_bytes;
 
 #[derive(Parser)]
@@ -11,6 +12,7 @@ pub struct XtaskOptions {
 #[derive(Parser)]
 enum Command {
     GenerateZeroBytes(zero_bytes::Options),
+    VerifyConstants(verify_constants::Options),
 }
 
 fn main() -> Result<(), anyhow::Error> {
@@ -18,6 +20,7 @@ fn main() -> Result<(), anyhow::Error> {
 
     match opts.command {
         Command::GenerateZeroBytes(opts) => zero_bytes::generate_zero_bytes(opts)?,
+        Command::VerifyConstants(opts) => verify_constants::verify_constants(opts)?,
     }
 
     Ok(())
@@ -0,0 +1,113 @@
+use clap::Parser;
+
+use light_merkle_tree::{
+    constants::{self, ZeroBytes},
+    HASH_LEN,
+};
+
+use crate::zero_bytes::{constants_path, zero_bytes, EmptyLeaf, Hash};
+
+#[derive(Debug, Parser)]
+pub struct Options {
+    /// Hash to verify. Can be specified multiple times. Defaults to all
+    /// hashes.
+    #[clap(value_enum, long)]
+    hash: Vec<Hash>,
+}
+
+/// Returns the committed zero bytes for the given hash, together with the
+/// empty leaf they were generated from.
+fn committed_zero_bytes(hash: Hash) -> Vec<(EmptyLeaf, &'static ZeroBytes)> {
+    let (zero_bytes, zero_bytes_zero) = match hash {
+        Hash::Sha256 => (
+            &constants::sha256::ZERO_BYTES,
+            &constants::sha256_zero::ZERO_BYTES,
+        ),
+        Hash::Keccak => (
+            &constants::keccak::ZERO_BYTES,
+            &constants::keccak_zero::ZERO_BYTES,
+        ),
+        Hash::Blake3 => (
+            &constants::blake3::ZERO_BYTES,
+            &constants::blake3_zero::ZERO_BYTES,
+        ),
+        Hash::Poseidon => (
+            &constants::poseidon::ZERO_BYTES,
+            &constants::poseidon_zero::ZERO_BYTES,
+        ),
+        // Only Tornado Cash zero bytes are committed for MiMC sponge.
+        Hash::MimcSponge => {
+            return vec![(
+                EmptyLeaf::tornado(),
+                &constants::mimc_sponge_tornado::ZERO_BYTES,
+            )]
+        }
+    };
+
+    vec![
+        (EmptyLeaf::default_preimage(), zero_bytes),
+        (EmptyLeaf::Value([0u8; HASH_LEN]), zero_bytes_zero),
+    ]
+}
+
+/// Regenerates zero bytes for the given hash and compares them with the
+/// committed ones, returning an error with the first differing level.
+pub fn verify_zero_bytes(hash: Hash) -> Result<(), anyhow::Error> {
+    for (empty_leaf, committed) in committed_zero_bytes(hash) {
+        let generated = zero_bytes(hash, &empty_leaf, committed.len(), 2);
+
+        if let Some((level, (committed, generated))) = committed
+            .iter()
+            .zip(generated.iter())
+            .enumerate()
+            .find(|(_, (committed, generated))| committed != generated)
+        {
+            anyhow::bail!(
+                "{:?} zero bytes in {:?} differ at level {}: committed {:?}, generated {:?}",
+                hash,
+                constants_path(hash, &empty_leaf).unwrap(),
+                level,
+                committed,
+                generated
+            );
+        }
+
+        println!(
+            "{:?} zero bytes match {:?}",
+            hash,
+            constants_path(hash, &empty_leaf).unwrap()
+        );
+    }
+
+    Ok(())
+}
+
+pub fn verify_constants(opts: Options) -> Result<(), anyhow::Error> {
+    let hashes = if opts.hash.is_empty() {
+        vec![
+            Hash::Sha256,
+            Hash::Keccak,
+            Hash::Blake3,
+            Hash::Poseidon,
+            Hash::MimcSponge,
+        ]
+    } else {
+        opts.hash
+    };
+
+    for hash in hashes {
+        verify_zero_bytes(hash)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_sha256() {
+        verify_zero_bytes(Hash::Sha256).unwrap();
+    }
+}
@@ -7,25 +7,186 @@ use std::{
     thread::spawn,
 };
 
+use ark_ff::{BigInteger, PrimeField};
 use clap::{Parser, ValueEnum};
 use quote::quote;
-use sha2::{Digest, Sha256};
 
-use light_merkle_tree::HASH_LEN;
+use light_merkle_tree::{
+    hasher::{Blake3, Hasher, Keccak, MimcSponge, Poseidon, Sha256},
+    HASH_LEN, MAX_HEIGHT,
+};
 
-#[derive(Debug, Clone, ValueEnum)]
-enum Hash {
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Hash {
     Sha256,
+    Keccak,
+    Blake3,
+    Poseidon,
+    MimcSponge,
+}
+
+impl Hash {
+    /// Name of the module in `light_merkle_tree::constants` holding zero
+    /// bytes for the hash.
+    pub fn module_name(&self) -> &'static str {
+        match self {
+            Hash::Sha256 => "sha256",
+            Hash::Keccak => "keccak",
+            Hash::Blake3 => "blake3",
+            Hash::Poseidon => "poseidon",
+            Hash::MimcSponge => "mimc_sponge",
+        }
+    }
+}
+
+/// Source of the empty leaf, which is the first level of zero bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmptyLeaf {
+    /// Chunks hashed together to get a seed. The empty leaf is a hash of two
+    /// copies of the seed.
+    Preimage(Vec<Vec<u8>>),
+    /// Value of the empty leaf.
+    Value([u8; HASH_LEN]),
+}
+
+impl EmptyLeaf {
+    /// Empty leaf used by the default zero bytes of each hash, derived from
+    /// the `[1; 32] || [1; 32]` preimage.
+    pub fn default_preimage() -> Self {
+        EmptyLeaf::Preimage(vec![vec![1u8; HASH_LEN], vec![1u8; HASH_LEN]])
+    }
+
+    /// Empty leaf of Tornado Cash, `keccak256("tornado") % FIELD_SIZE`.
+    pub fn tornado() -> Self {
+        let value = ark_bn254::Fr::from_be_bytes_mod_order(&Keccak::hash(b"tornado"));
+        EmptyLeaf::Value(value.into_bigint().to_bytes_be().try_into().unwrap())
+    }
+
+    /// Suffix of the constants module holding zero bytes for this empty leaf.
+    fn module_suffix(&self) -> Option<&'static str> {
+        if *self == Self::default_preimage() {
+            Some("")
+        } else if *self == EmptyLeaf::Value([0u8; HASH_LEN]) {
+            Some("_zero")
+        } else if *self == Self::tornado() {
+            Some("_tornado")
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Parser)]
 pub struct Options {
     #[clap(value_enum, long, default_value_t = Hash::Sha256)]
     hash: Hash,
+    /// Number of levels to generate.
+    #[clap(long, default_value_t = MAX_HEIGHT + 1)]
+    depth: usize,
+    /// Number of children of each node.
+    #[clap(long, default_value_t = 2)]
+    arity: usize,
+    /// Hex-encoded 32-byte chunks, hashed together to get the seed of the
+    /// zero bytes chain. Can be specified multiple times.
+    #[clap(long = "preimage", default_values_t = default_preimage())]
+    preimage: Vec<String>,
+    /// Hex-encoded value of the empty leaf. Takes precedence over
+    /// `--preimage`.
+    #[clap(long)]
+    empty_leaf: Option<String>,
+    /// Path of the generated file. Defaults to the module in
+    /// `light-merkle-tree/src/constants` for the default preimage and for
+    /// the all-zero empty leaf.
     #[clap(long)]
     path: Option<PathBuf>,
 }
 
+fn default_preimage() -> Vec<String> {
+    vec![hex_encode(&[1u8; HASH_LEN]), hex_encode(&[1u8; HASH_LEN])]
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn hex_decode(s: &str) -> Result<Vec<u8>, anyhow::Error> {
+    anyhow::ensure!(s.len().is_multiple_of(2), "odd length of hex string {s}");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| Ok(u8::from_str_radix(&s[i..i + 2], 16)?))
+        .collect()
+}
+
+impl Options {
+    fn empty_leaf(&self) -> Result<EmptyLeaf, anyhow::Error> {
+        match &self.empty_leaf {
+            Some(value) => {
+                let value = <[u8; HASH_LEN]>::try_from(hex_decode(value)?)
+                    .map_err(|_| anyhow::anyhow!("empty leaf has to be {HASH_LEN} bytes long"))?;
+                Ok(EmptyLeaf::Value(value))
+            }
+            None => Ok(EmptyLeaf::Preimage(
+                self.preimage
+                    .iter()
+                    .map(|chunk| hex_decode(chunk))
+                    .collect::<Result<_, _>>()?,
+            )),
+        }
+    }
+}
+
+/// Returns the path of the committed zero bytes module for the given hash
+/// and empty leaf, if there is one.
+pub fn constants_path(hash: Hash, empty_leaf: &EmptyLeaf) -> Option<PathBuf> {
+    empty_leaf.module_suffix().map(|suffix| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../light-merkle-tree/src/constants")
+            .join(format!("{}{}.rs", hash.module_name(), suffix))
+    })
+}
+
+/// Computes `depth` levels of zero bytes, starting with the empty leaf.
+/// Every next level is a hash of `arity` copies of the previous one.
+fn zero_bytes_with<H: Hasher>(
+    empty_leaf: &EmptyLeaf,
+    depth: usize,
+    arity: usize,
+) -> Vec<[u8; HASH_LEN]> {
+    let mut prev_hash = match empty_leaf {
+        EmptyLeaf::Preimage(preimage) => {
+            let preimage: Vec<&[u8]> = preimage.iter().map(|chunk| chunk.as_slice()).collect();
+            let seed = H::hashv(&preimage);
+            H::hashv(&[&seed, &seed])
+        }
+        EmptyLeaf::Value(value) => *value,
+    };
+
+    let mut zero_bytes = Vec::with_capacity(depth);
+    for _ in 0..depth {
+        zero_bytes.push(prev_hash);
+        prev_hash = H::hashv(&vec![&prev_hash[..]; arity]);
+    }
+
+    zero_bytes
+}
+
+/// Computes `depth` levels of zero bytes of a tree with the given hash and
+/// arity.
+pub fn zero_bytes(
+    hash: Hash,
+    empty_leaf: &EmptyLeaf,
+    depth: usize,
+    arity: usize,
+) -> Vec<[u8; HASH_LEN]> {
+    match hash {
+        Hash::Sha256 => zero_bytes_with::<Sha256>(empty_leaf, depth, arity),
+        Hash::Keccak => zero_bytes_with::<Keccak>(empty_leaf, depth, arity),
+        Hash::Blake3 => zero_bytes_with::<Blake3>(empty_leaf, depth, arity),
+        Hash::Poseidon => zero_bytes_with::<Poseidon>(empty_leaf, depth, arity),
+        Hash::MimcSponge => zero_bytes_with::<MimcSponge>(empty_leaf, depth, arity),
+    }
+}
+
 fn rustfmt(code: String) -> Result<Vec<u8>, anyhow::Error> {
     let mut cmd = match env::var_os("RUSTFMT") {
         Some(r) => Command::new(r),
@@ -54,37 +215,11 @@ fn rustfmt(code: String) -> Result<Vec<u8>, anyhow::Error> {
     Ok(formatted_code)
 }
 
-pub fn generate_zero_bytes(opts: Options) -> Result<(), anyhow::Error> {
-    let mut hasher = match opts.hash {
-        Hash::Sha256 => Sha256::new(),
-    };
-
-    let mut zero_bytes = [[0u8; 32]; 19];
-    let mut zero_bytes_tokens = vec![];
-
-    hasher.update([1u8; 32]);
-    hasher.update([1u8; 32]);
-
-    let mut prev_hash = <[u8; HASH_LEN]>::try_from(hasher.finalize_reset().to_vec()).unwrap();
-
-    for i in 0..19 {
-        hasher.update(prev_hash);
-        hasher.update(prev_hash);
-
-        let cur_hash = <[u8; HASH_LEN]>::try_from(hasher.finalize_reset().to_vec()).unwrap();
-        zero_bytes[i] = cur_hash;
-
-        let cur_hash_iter = cur_hash.iter();
-        zero_bytes_tokens.push(quote! {
-            [ #(#cur_hash_iter),* ]
-        });
-
-        prev_hash = cur_hash;
-    }
-
+/// Renders the zero bytes as a Rust module.
+pub fn zero_bytes_code(zero_bytes: &[[u8; HASH_LEN]]) -> Result<Vec<u8>, anyhow::Error> {
     // NOTE(vadorovsky): I couldn't find any way to do a double repetition
     // over a 2D array inside `quote` macro, that's why arrays are converted
-    // to tokens in the loop above. But I would be grateful if there is any
+    // to tokens in the loop below. But I would be grateful if there is any
     // way to make it prettier.
     //
     // Being able to do something like:
@@ -96,23 +231,64 @@ pub fn generate_zero_bytes(opts: Options) -> Result<(), anyhow::Error> {
     // ```
     //
     // would be great.
-    let code = quote! {
-        use super::ZeroBytes;
+    let zero_bytes_tokens = zero_bytes.iter().map(|hash| {
+        let hash_iter = hash.iter();
+        quote! {
+            [ #(#hash_iter),* ]
+        }
+    });
+
+    // The `ZeroBytes` type is used only when the depth matches it, so custom
+    // depths still produce valid code.
+    let code = if zero_bytes.len() == MAX_HEIGHT + 1 {
+        quote! {
+            use super::ZeroBytes;
 
-        pub const ZERO_BYTES: ZeroBytes = [ #(#zero_bytes_tokens),* ];
+            pub const ZERO_BYTES: ZeroBytes = [ #(#zero_bytes_tokens),* ];
+        }
+    } else {
+        let depth = zero_bytes.len();
+        quote! {
+            pub const ZERO_BYTES: [[u8; 32]; #depth] = [ #(#zero_bytes_tokens),* ];
+        }
     };
 
+    let mut file = b"// This file is generated by xtask. Do not edit it manually.\n\n".to_vec();
+    file.extend(rustfmt(code.to_string())?);
+
+    Ok(file)
+}
+
+pub fn generate_zero_bytes(opts: Options) -> Result<(), anyhow::Error> {
+    anyhow::ensure!(opts.arity >= 2, "arity has to be at least 2");
+    anyhow::ensure!(
+        !matches!(opts.hash, Hash::Poseidon) || opts.arity <= Poseidon::MAX_INPUTS,
+        "arity of a Poseidon tree can be at most {}",
+        Poseidon::MAX_INPUTS
+    );
+    let empty_leaf = opts.empty_leaf()?;
+    let zero_bytes = zero_bytes(opts.hash, &empty_leaf, opts.depth, opts.arity);
+
     println!(
         "Zero bytes (generated with {:?} hash): {:?}",
         opts.hash, zero_bytes
     );
 
-    if let Some(path) = opts.path {
-        let mut file = File::create(&path)?;
-        file.write_all(b"// This file is generated by xtask. Do not edit it manually.\n\n")?;
-        file.write_all(&rustfmt(code.to_string())?)?;
-        println!("Zero bytes written to {:?}", path);
-    }
+    let path = match opts.path {
+        Some(path) => path,
+        None if opts.arity != 2 => {
+            anyhow::bail!(
+                "no constants module for trees with arity {}, use --path",
+                opts.arity
+            )
+        }
+        None => constants_path(opts.hash, &empty_leaf).ok_or_else(|| {
+            anyhow::anyhow!("no constants module for the given empty leaf, use --path")
+        })?,
+    };
+    let mut file = File::create(&path)?;
+    file.write_all(&zero_bytes_code(&zero_bytes)?)?;
+    println!("Zero bytes written to {:?}", path);
 
     Ok(())
 }